@@ -0,0 +1,197 @@
+//! Time and peak heap use of generating truncated spheres, then of building
+//! the vertex to triangle map and the tile rows as `Adjacency` and in the
+//! `HashMap` and per-tile `Vec` layout generation used before it. Run with
+//! `cargo bench --bench generation`, optionally followed by `-- <max detail>`
+//! (default 7; detail 9 has about 2.6 million tiles).
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use web_hex::{Adjacency, BaseSolid, Polyhedron, Projection, TileTopology, Triangle};
+
+/// Counts live and peak heap bytes on top of the system allocator.
+struct Counting;
+
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let live = LIVE.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+        PEAK.fetch_max(live, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// Runs `generate`, returning how long it took, the peak heap use above what
+/// was live beforehand and the heap still held by its result.
+fn measure<T>(generate: impl FnOnce() -> T) -> (Duration, usize, usize) {
+    let before = LIVE.load(Ordering::Relaxed);
+    PEAK.store(before, Ordering::Relaxed);
+    let start = Instant::now();
+    let result = generate();
+    let elapsed = start.elapsed();
+    let retained = LIVE.load(Ordering::Relaxed) - before;
+    let peak = PEAK.load(Ordering::Relaxed) - before;
+    drop(result);
+    (elapsed, peak, retained)
+}
+
+fn megabytes(bytes: usize) -> f64 {
+    bytes as f64 / (1024.0 * 1024.0)
+}
+
+/// The vertex to triangle map as generation built it before `Adjacency`.
+fn hash_map_vert_to_faces(cells: &[Triangle]) -> HashMap<usize, Vec<usize>> {
+    let mut vert_to_faces: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (i, triangle) in cells.iter().enumerate() {
+        for vertex in triangle.corners() {
+            if let Some(faces) = vert_to_faces.get_mut(&vertex) {
+                faces.push(i);
+            } else {
+                vert_to_faces.insert(vertex, vec![i]);
+            }
+        }
+    }
+    vert_to_faces
+}
+
+/// A tile as polyhedra kept them before `Adjacency`.
+struct OldTile {
+    _center: usize,
+    _boundary: Vec<usize>,
+    _neighbours: Vec<usize>,
+}
+
+/// `(tile, index)` for every entry of one of the tile rows.
+fn tile_pairs<'a>(
+    polyhedron: &'a Polyhedron,
+    row: fn(TileTopology<'a>) -> &'a [u32],
+) -> impl Iterator<Item = (usize, usize)> + Clone + 'a {
+    (0..polyhedron.tile_count()).flat_map(move |tile_index| {
+        row(polyhedron.tile(tile_index))
+            .iter()
+            .map(move |target| (tile_index, *target as usize))
+    })
+}
+
+fn print_layout(detail: u32, relation: &str, layout: &str, measured: (Duration, usize, usize)) {
+    let (elapsed, peak, retained) = measured;
+    println!(
+        "{:>6} {:>20} {:>8} {:>10.2?} {:>12.1} {:>14.1}",
+        detail,
+        relation,
+        layout,
+        elapsed,
+        megabytes(peak),
+        megabytes(retained),
+    );
+}
+
+fn main() {
+    let max_detail = std::env::args()
+        .skip(1)
+        .find_map(|arg| arg.parse().ok())
+        .unwrap_or(7);
+
+    println!(
+        "{:>6} {:>9} {:>8} {:>10} {:>12} {:>14}",
+        "detail", "tiles", "threads", "time", "peak MiB", "retained MiB"
+    );
+    for detail in 4..=max_detail {
+        for threads in [1, 0] {
+            let (elapsed, peak, retained) = measure(|| {
+                Polyhedron::<f32>::new_truncated_geodesic_parallel(
+                    BaseSolid::Icosahedron,
+                    1.0,
+                    1 << detail,
+                    0,
+                    Projection::Gnomonic,
+                    threads,
+                )
+                .unwrap()
+            });
+            println!(
+                "{:>6} {:>9} {:>8} {:>10.2?} {:>12.1} {:>14.1}",
+                detail,
+                10 * (1usize << (2 * detail)) + 2,
+                if threads == 0 { "all" } else { "1" },
+                elapsed,
+                megabytes(peak),
+                megabytes(retained),
+            );
+        }
+    }
+
+    println!(
+        "\n{:>6} {:>20} {:>8} {:>10} {:>12} {:>14}",
+        "detail", "relation", "layout", "time", "peak MiB", "retained MiB"
+    );
+    for detail in 4..=max_detail {
+        let geodesic = Polyhedron::<f32>::new_isocahedron(1.0, detail).unwrap();
+        let corners = || {
+            geodesic
+                .cells
+                .iter()
+                .enumerate()
+                .flat_map(|(triangle_index, triangle)| {
+                    triangle.corners().map(|vertex| (vertex, triangle_index))
+                })
+        };
+        print_layout(
+            detail,
+            "vertex triangles",
+            "HashMap",
+            measure(|| hash_map_vert_to_faces(&geodesic.cells)),
+        );
+        print_layout(
+            detail,
+            "vertex triangles",
+            "CSR",
+            measure(|| Adjacency::from_pairs(geodesic.positions.len(), corners())),
+        );
+
+        let truncated = geodesic.into_truncated().unwrap();
+        let tiles = truncated.tile_count();
+        print_layout(
+            detail,
+            "tile rows",
+            "Vec<Vec>",
+            measure(|| {
+                truncated
+                    .tiles()
+                    .map(|tile| OldTile {
+                        _center: tile.center as usize,
+                        _boundary: tile.boundary.iter().map(|c| *c as usize).collect(),
+                        _neighbours: tile.neighbours.iter().map(|n| *n as usize).collect(),
+                    })
+                    .collect::<Vec<_>>()
+            }),
+        );
+        print_layout(
+            detail,
+            "tile rows",
+            "CSR",
+            measure(|| {
+                (
+                    truncated
+                        .tiles()
+                        .map(|tile| tile.center)
+                        .collect::<Vec<_>>(),
+                    Adjacency::from_pairs(tiles, tile_pairs(&truncated, |tile| tile.boundary)),
+                    Adjacency::from_pairs(tiles, tile_pairs(&truncated, |tile| tile.neighbours)),
+                )
+            }),
+        );
+    }
+}
@@ -0,0 +1,71 @@
+//! Size and load time of a truncated sphere saved as pretty JSON, as the
+//! renderer's `Tiles` in pretty JSON, and in the binary format. Run with
+//! `cargo bench --bench loading`, optionally followed by `-- <max detail>`
+//! (default 7; detail 6 has about 41 thousand tiles).
+
+use std::time::{Duration, Instant};
+
+use web_hex::{Polyhedron, Tiles};
+
+/// Runs `load` a few times and returns the fastest run.
+fn fastest<T>(load: impl Fn() -> T) -> Duration {
+    (0..3)
+        .map(|_| {
+            let start = Instant::now();
+            drop(load());
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn megabytes(bytes: usize) -> f64 {
+    bytes as f64 / (1024.0 * 1024.0)
+}
+
+fn main() {
+    let max_detail = std::env::args()
+        .skip(1)
+        .find_map(|arg| arg.parse().ok())
+        .unwrap_or(7);
+
+    println!(
+        "{:>6} {:>9} {:>12} {:>10} {:>10}",
+        "detail", "tiles", "format", "MiB", "load"
+    );
+    for detail in 4..=max_detail {
+        let polyhedron = Polyhedron::<f32>::new_truncated_isocahedron(1.0, detail).unwrap();
+        let json = serde_json::to_vec_pretty(&polyhedron).unwrap();
+        let tiles = serde_json::to_vec_pretty(&polyhedron.to_tiles()).unwrap();
+        let mut binary = vec![];
+        polyhedron.write_binary(&mut binary).unwrap();
+
+        let rows = [
+            (
+                "json",
+                json.len(),
+                fastest(|| serde_json::from_slice::<Polyhedron>(&json).unwrap()),
+            ),
+            (
+                "tiles json",
+                tiles.len(),
+                fastest(|| serde_json::from_slice::<Tiles>(&tiles).unwrap()),
+            ),
+            (
+                "binary",
+                binary.len(),
+                fastest(|| Polyhedron::<f32>::read_binary(&mut binary.as_slice()).unwrap()),
+            ),
+        ];
+        for (format, bytes, elapsed) in rows {
+            println!(
+                "{:>6} {:>9} {:>12} {:>10.1} {:>10.2?}",
+                detail,
+                polyhedron.tile_count(),
+                format,
+                megabytes(bytes),
+                elapsed,
+            );
+        }
+    }
+}
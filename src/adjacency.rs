@@ -0,0 +1,156 @@
+use std::fmt;
+use std::ops::Index;
+
+use serde::de::{Deserializer, SeqAccess, Visitor};
+use serde::ser::{SerializeSeq, Serializer};
+use serde::{Deserialize, Serialize};
+
+/// Rows of indices stored flat, compressed sparse row style: row `i` is
+/// `targets[offsets[i]..offsets[i + 1]]`. Used for every one-to-many relation
+/// of a polyhedron, such as the triangles around a vertex or the neighbours
+/// of a tile, so large spheres need two allocations per relation rather than
+/// one per row. Offsets and targets are `u32`, half the size of `usize`, as
+/// generation keeps every count in range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Adjacency {
+    offsets: Vec<u32>,
+    targets: Vec<u32>,
+}
+
+impl Default for Adjacency {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Adjacency {
+    pub fn new() -> Adjacency {
+        Adjacency {
+            offsets: vec![0],
+            targets: vec![],
+        }
+    }
+
+    /// `count` rows of one entry each, `0..count`.
+    pub(crate) fn identity(count: usize) -> Adjacency {
+        Adjacency {
+            offsets: (0..=count as u32).collect(),
+            targets: (0..count as u32).collect(),
+        }
+    }
+
+    /// `rows` rows holding the targets of the `(row, target)` pairs, in the
+    /// order the pairs are given. Walks `pairs` twice, counting first.
+    pub fn from_pairs(
+        rows: usize,
+        pairs: impl Iterator<Item = (usize, usize)> + Clone,
+    ) -> Adjacency {
+        let mut offsets = vec![0; rows + 1];
+        for (row, _) in pairs.clone() {
+            offsets[row + 1] += 1;
+        }
+        for row in 0..rows {
+            offsets[row + 1] += offsets[row];
+        }
+        let mut next = offsets.clone();
+        let mut targets = vec![0; offsets[rows] as usize];
+        for (row, target) in pairs {
+            targets[next[row] as usize] = target as u32;
+            next[row] += 1;
+        }
+        Adjacency { offsets, targets }
+    }
+
+    /// Rows of the given lengths taking `targets` in order; the lengths must
+    /// add up to `targets.len()`.
+    pub(crate) fn from_lengths(lengths: &[u32], targets: Vec<u32>) -> Adjacency {
+        let mut offsets = Vec::with_capacity(lengths.len() + 1);
+        offsets.push(0);
+        for length in lengths {
+            offsets.push(offsets[offsets.len() - 1] + length);
+        }
+        debug_assert_eq!(offsets[lengths.len()] as usize, targets.len());
+        Adjacency { offsets, targets }
+    }
+
+    pub(crate) fn push_row(&mut self, row: impl IntoIterator<Item = u32>) {
+        self.targets.extend(row);
+        self.offsets.push(self.targets.len() as u32);
+    }
+
+    /// Number of rows.
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, row: usize) -> Option<&[u32]> {
+        (row < self.len()).then(|| &self[row])
+    }
+
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &[u32]> + '_ {
+        self.offsets
+            .windows(2)
+            .map(|range| &self.targets[range[0] as usize..range[1] as usize])
+    }
+
+    /// Every target of every row, row by row.
+    pub fn targets(&self) -> &[u32] {
+        &self.targets
+    }
+}
+
+impl Index<usize> for Adjacency {
+    type Output = [u32];
+
+    fn index(&self, row: usize) -> &[u32] {
+        &self.targets[self.offsets[row] as usize..self.offsets[row + 1] as usize]
+    }
+}
+
+/// Serializes as a list of rows.
+impl Serialize for Adjacency {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for row in self.iter() {
+            seq.serialize_element(row)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Adjacency {
+    fn deserialize<D>(deserializer: D) -> Result<Adjacency, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct RowsVisitor;
+
+        impl<'de> Visitor<'de> for RowsVisitor {
+            type Value = Adjacency;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a list of lists of indices")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Adjacency, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut adjacency = Adjacency::new();
+                while let Some(row) = seq.next_element::<Vec<u32>>()? {
+                    adjacency.push_row(row);
+                }
+                Ok(adjacency)
+            }
+        }
+
+        deserializer.deserialize_seq(RowsVisitor)
+    }
+}
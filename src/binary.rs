@@ -0,0 +1,398 @@
+use std::io::{self, Read, Write};
+use std::mem;
+
+use cgmath::{BaseFloat, Vector3};
+
+use crate::serialization::{PolyhedronData, TileRows};
+use crate::{
+    Adjacency, ArraySerializedVector, BaseSolid, Lattice, Polyhedron, Projection, Triangle,
+};
+
+const MAGIC: &[u8; 4] = b"WHEX";
+/// Bumped whenever the layout changes; readers reject versions they do not
+/// know.
+const VERSION: u16 = 1;
+const HEADER_LENGTH: usize = 44;
+const HAS_LATTICE: u8 = 1;
+
+const PROJECTIONS: [Projection; 3] = [
+    Projection::Gnomonic,
+    Projection::Slerp,
+    Projection::EqualArea,
+];
+
+/// Binary files, a compact alternative to JSON for large spheres.
+///
+/// All numbers are little-endian. A 44 byte header holds the magic `WHEX`,
+/// the format version (`u16`), the width of a scalar in bytes (`u8`, 4 or 8),
+/// flags (`u8`, bit 0 set when the lattice is saved), the number of vertices,
+/// triangles, faces, face triangles, tiles and tile corners (`u32` each), the
+/// payload length (`u64`) and a CRC-32 of the preceding header bytes and the
+/// payload (`u32`). The payload follows with positions and normals (three
+/// scalars per vertex), colours (three `f32`), triangles (three `u32`), the
+/// length of each face then their triangles, the center of each tile, the
+/// length of each tile, then the tile corners and the neighbours across each
+/// of their edges (all `u32`), and last the lattice if there is one: base
+/// solid and projection (`u8`), m and n (`u32`) and radius (scalar). Tile
+/// addresses are worked out from the lattice, so are not saved.
+impl<S: BaseFloat> Polyhedron<S> {
+    /// Writes the polyhedron in the binary format, with everything
+    /// `read_binary` needs to load it back.
+    pub fn write_binary<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let scalar_width = mem::size_of::<S>() as u8;
+        let mut payload = vec![];
+        for vectors in [&self.positions, &self.normals] {
+            for vector in vectors {
+                for value in [vector.0.x, vector.0.y, vector.0.z] {
+                    put_scalar(&mut payload, value, scalar_width);
+                }
+            }
+        }
+        for color in &self.colors {
+            for value in [color.0.x, color.0.y, color.0.z] {
+                payload.extend(value.to_le_bytes());
+            }
+        }
+        for cell in &self.cells {
+            put_u32s(&mut payload, &[cell.a, cell.b, cell.c]);
+        }
+        put_indices(&mut payload, self.faces.iter().map(|face| face.len()))?;
+        put_u32s(&mut payload, self.faces.targets());
+        put_u32s(&mut payload, &self.tile_centers);
+        put_indices(
+            &mut payload,
+            self.tile_boundaries.iter().map(|tile| tile.len()),
+        )?;
+        put_u32s(&mut payload, self.tile_boundaries.targets());
+        put_u32s(&mut payload, self.tile_neighbours.targets());
+        let mut flags = 0;
+        if let Some(lattice) = &self.lattice {
+            flags |= HAS_LATTICE;
+            payload.push(position_of(&BaseSolid::ALL, lattice.base));
+            payload.push(position_of(&PROJECTIONS, lattice.projection));
+            put_indices(&mut payload, [lattice.m as usize, lattice.n as usize])?;
+            put_scalar(&mut payload, lattice.radius, scalar_width);
+        }
+
+        let mut header = Vec::with_capacity(HEADER_LENGTH);
+        header.extend(MAGIC);
+        header.extend(VERSION.to_le_bytes());
+        header.extend([scalar_width, flags]);
+        put_indices(
+            &mut header,
+            [
+                self.positions.len(),
+                self.cells.len(),
+                self.faces.len(),
+                self.faces.targets().len(),
+                self.tile_count(),
+                self.tile_boundaries.targets().len(),
+            ],
+        )?;
+        header.extend((payload.len() as u64).to_le_bytes());
+        let checksum = crc32(crc32(!0, &header), &payload);
+        header.extend((!checksum).to_le_bytes());
+
+        writer.write_all(&header)?;
+        writer.write_all(&payload)
+    }
+
+    /// Reads a polyhedron written by `write_binary`, checking the checksum
+    /// and every index before rebuilding it. Files saved with a different
+    /// scalar type are converted.
+    pub fn read_binary<R: Read>(reader: &mut R) -> io::Result<Polyhedron<S>> {
+        let mut header = [0; HEADER_LENGTH];
+        reader.read_exact(&mut header)?;
+        let mut fields = Cursor::new(&header);
+        if fields.bytes(4)? != MAGIC {
+            return Err(invalid("not a binary polyhedron"));
+        }
+        let version = fields.u16()?;
+        if version != VERSION {
+            return Err(invalid(format!("unsupported format version {version}")));
+        }
+        let scalar_width = fields.u8()?;
+        if scalar_width != 4 && scalar_width != 8 {
+            return Err(invalid(format!("unsupported scalar width {scalar_width}")));
+        }
+        let flags = fields.u8()?;
+        let mut counts = [0; 6];
+        for count in &mut counts {
+            *count = fields.index()?;
+        }
+        let [vertices, triangles, faces, face_triangles, tiles, corners] = counts;
+        let payload_length = fields.u64()?;
+        let checksum = fields.u32()?;
+
+        let scalar_width = scalar_width as u64;
+        let expected_length = 6 * scalar_width * vertices as u64
+            + 12 * vertices as u64
+            + 12 * triangles as u64
+            + 4 * (faces + face_triangles) as u64
+            + 8 * (tiles + corners) as u64
+            + if flags & HAS_LATTICE != 0 {
+                10 + scalar_width
+            } else {
+                0
+            };
+        if payload_length != expected_length {
+            return Err(invalid(format!(
+                "payload is {payload_length} bytes but the header describes {expected_length}"
+            )));
+        }
+        let mut payload = vec![];
+        payload
+            .try_reserve_exact(payload_length as usize)
+            .map_err(|_| invalid(format!("no room for a {payload_length} byte payload")))?;
+        reader.take(payload_length).read_to_end(&mut payload)?;
+        if payload.len() as u64 != payload_length {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        if !crc32(crc32(!0, &header[..HEADER_LENGTH - 4]), &payload) != checksum {
+            return Err(invalid("checksum mismatch"));
+        }
+
+        let mut payload = Cursor::new(&payload);
+        let scalar_width = scalar_width as u8;
+        let positions = payload.vectors(vertices, scalar_width)?;
+        let normals = payload.vectors(vertices, scalar_width)?;
+        let colors = payload
+            .values(3 * vertices, f32::from_le_bytes)?
+            .chunks_exact(3)
+            .map(|color| ArraySerializedVector(Vector3::new(color[0], color[1], color[2])))
+            .collect();
+        let cells = payload
+            .u32s(3 * triangles)?
+            .chunks_exact(3)
+            .map(|cell| Triangle {
+                a: cell[0],
+                b: cell[1],
+                c: cell[2],
+            })
+            .collect();
+        let face_lengths = payload.u32s(faces)?;
+        let faces = payload.rows(&face_lengths, face_triangles)?;
+        let centers = payload.u32s(tiles)?;
+        let tile_lengths = payload.u32s(tiles)?;
+        let boundaries = payload.rows(&tile_lengths, corners)?;
+        let neighbours = payload.rows(&tile_lengths, corners)?;
+        let lattice = if flags & HAS_LATTICE != 0 {
+            let base = payload.u8()?;
+            let projection = payload.u8()?;
+            let (m, n) = (payload.index()?, payload.index()?);
+            Some(Lattice {
+                base: *BaseSolid::ALL
+                    .get(base as usize)
+                    .ok_or_else(|| invalid(format!("unknown base solid {base}")))?,
+                m: m as i64,
+                n: n as i64,
+                radius: payload.scalar(scalar_width)?,
+                projection: *PROJECTIONS
+                    .get(projection as usize)
+                    .ok_or_else(|| invalid(format!("unknown projection {projection}")))?,
+            })
+        } else {
+            None
+        };
+
+        Polyhedron::from_data(PolyhedronData {
+            positions,
+            cells,
+            normals,
+            colors,
+            faces: Some(faces),
+            tiles: TileRows {
+                centers,
+                boundaries,
+                neighbours,
+            },
+            lattice,
+        })
+        .map_err(invalid)
+    }
+}
+
+fn invalid(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+fn position_of<T: PartialEq>(values: &[T], value: T) -> u8 {
+    values.iter().position(|v| *v == value).unwrap() as u8
+}
+
+fn put_scalar<S: BaseFloat>(bytes: &mut Vec<u8>, value: S, width: u8) {
+    let value = value.to_f64().unwrap();
+    match width {
+        4 => bytes.extend((value as f32).to_le_bytes()),
+        _ => bytes.extend(value.to_le_bytes()),
+    }
+}
+
+fn put_indices(bytes: &mut Vec<u8>, indices: impl IntoIterator<Item = usize>) -> io::Result<()> {
+    for index in indices {
+        let index =
+            u32::try_from(index).map_err(|_| invalid(format!("{index} does not fit in a u32")))?;
+        bytes.extend(index.to_le_bytes());
+    }
+    Ok(())
+}
+
+fn put_u32s(bytes: &mut Vec<u8>, values: &[u32]) {
+    for value in values {
+        bytes.extend(value.to_le_bytes());
+    }
+}
+
+/// Reads little-endian values off the front of a byte slice.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn new(bytes: &'a [u8]) -> Cursor<'a> {
+        Cursor { bytes }
+    }
+
+    fn bytes(&mut self, count: usize) -> io::Result<&'a [u8]> {
+        if self.bytes.len() < count {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        let (taken, rest) = self.bytes.split_at(count);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn array<const N: usize>(&mut self) -> io::Result<[u8; N]> {
+        Ok(self.bytes(N)?.try_into().unwrap())
+    }
+
+    fn u8(&mut self) -> io::Result<u8> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    fn u16(&mut self) -> io::Result<u16> {
+        Ok(u16::from_le_bytes(self.array()?))
+    }
+
+    fn u32(&mut self) -> io::Result<u32> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+
+    fn u64(&mut self) -> io::Result<u64> {
+        Ok(u64::from_le_bytes(self.array()?))
+    }
+
+    /// `count` values of `N` bytes each, decoded by `decode`.
+    fn values<const N: usize, T>(
+        &mut self,
+        count: usize,
+        decode: impl Fn([u8; N]) -> T,
+    ) -> io::Result<Vec<T>> {
+        Ok(self
+            .bytes(count * N)?
+            .chunks_exact(N)
+            .map(|value| decode(value.try_into().unwrap()))
+            .collect())
+    }
+
+    fn scalars<S: BaseFloat>(&mut self, count: usize, width: u8) -> io::Result<Vec<S>> {
+        let values = match width {
+            4 => self.values(count, |bytes| f32::from_le_bytes(bytes) as f64)?,
+            _ => self.values(count, f64::from_le_bytes)?,
+        };
+        values
+            .into_iter()
+            .map(|value| {
+                S::from(value)
+                    .ok_or_else(|| invalid(format!("{value} does not fit the scalar type")))
+            })
+            .collect()
+    }
+
+    fn scalar<S: BaseFloat>(&mut self, width: u8) -> io::Result<S> {
+        Ok(self.scalars(1, width)?[0])
+    }
+
+    fn vectors<S: BaseFloat>(
+        &mut self,
+        count: usize,
+        width: u8,
+    ) -> io::Result<Vec<ArraySerializedVector<S>>> {
+        Ok(self
+            .scalars(3 * count, width)?
+            .chunks_exact(3)
+            .map(|vector| ArraySerializedVector(Vector3::new(vector[0], vector[1], vector[2])))
+            .collect())
+    }
+
+    fn index(&mut self) -> io::Result<usize> {
+        Ok(self.u32()? as usize)
+    }
+
+    fn u32s(&mut self, count: usize) -> io::Result<Vec<u32>> {
+        self.values(count, u32::from_le_bytes)
+    }
+
+    /// Rows of the given lengths, `total` indices in all, read one after the
+    /// other.
+    fn rows(&mut self, lengths: &[u32], total: usize) -> io::Result<Adjacency> {
+        if lengths.iter().map(|length| *length as usize).sum::<usize>() != total {
+            return Err(invalid(format!("row lengths do not add up to {total}")));
+        }
+        Ok(Adjacency::from_lengths(lengths, self.u32s(total)?))
+    }
+}
+
+/// CRC-32 (IEEE) lookup tables for slicing by 8: `CRC_TABLES[0]` advances
+/// the CRC by one byte, `CRC_TABLES[k]` by a byte followed by `k` zero bytes.
+const CRC_TABLES: [[u32; 256]; 8] = {
+    let mut tables = [[0; 256]; 8];
+    let mut byte = 0;
+    while byte < 256 {
+        let mut crc = byte as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                0xEDB8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        tables[0][byte] = crc;
+        byte += 1;
+    }
+    let mut k = 1;
+    while k < 8 {
+        let mut byte = 0;
+        while byte < 256 {
+            let previous = tables[k - 1][byte];
+            tables[k][byte] = (previous >> 8) ^ tables[0][(previous & 0xFF) as usize];
+            byte += 1;
+        }
+        k += 1;
+    }
+    tables
+};
+
+/// Continues a CRC-32 over `bytes`, eight bytes at a time. Start from `!0`
+/// and invert the result.
+fn crc32(mut crc: u32, bytes: &[u8]) -> u32 {
+    let t = &CRC_TABLES;
+    let mut chunks = bytes.chunks_exact(8);
+    for chunk in &mut chunks {
+        let low = crc ^ u32::from_le_bytes(chunk[..4].try_into().unwrap());
+        let high = u32::from_le_bytes(chunk[4..].try_into().unwrap());
+        crc = t[7][(low & 0xFF) as usize]
+            ^ t[6][(low >> 8 & 0xFF) as usize]
+            ^ t[5][(low >> 16 & 0xFF) as usize]
+            ^ t[4][(low >> 24) as usize]
+            ^ t[3][(high & 0xFF) as usize]
+            ^ t[2][(high >> 8 & 0xFF) as usize]
+            ^ t[1][(high >> 16 & 0xFF) as usize]
+            ^ t[0][(high >> 24) as usize];
+    }
+    chunks.remainder().iter().fold(crc, |crc, byte| {
+        t[0][((crc ^ *byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
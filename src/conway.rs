@@ -0,0 +1,341 @@
+use std::collections::HashMap;
+
+use cgmath::prelude::*;
+use cgmath::{BaseFloat, Vector3};
+
+use crate::{scalar, ArraySerializedVector, Polyhedron, PolyhedronError, Triangle};
+
+/// A polyhedron with faces of any size, for Conway operators. Faces wind
+/// counter-clockwise seen from outside. New vertices made by an operator are
+/// placed on the sphere through the vertices they come from, so operators on
+/// a sphere tiling keep it on the sphere. Operators other than `kis` need
+/// every edge shared by exactly two faces running opposite ways.
+#[derive(Debug, Clone)]
+pub struct PolyMesh<S = f32> {
+    pub positions: Vec<Vector3<S>>,
+    pub faces: Vec<Vec<usize>>,
+}
+
+impl<S: BaseFloat> From<&Polyhedron<S>> for PolyMesh<S> {
+    /// The tiles of a truncated polyhedron, or the triangles of any other.
+    fn from(polyhedron: &Polyhedron<S>) -> PolyMesh<S> {
+        if polyhedron.tile_count() == 0 {
+            return PolyMesh {
+                positions: polyhedron
+                    .positions
+                    .iter()
+                    .map(|position| position.0)
+                    .collect(),
+                faces: polyhedron
+                    .cells
+                    .iter()
+                    .map(|cell| cell.corners().to_vec())
+                    .collect(),
+            };
+        }
+
+        // Keep only the tile corners, dropping centers and edge midpoints.
+        let mut corner_indices = HashMap::new();
+        let mut positions = vec![];
+        let faces = polyhedron
+            .tiles()
+            .map(|tile| {
+                tile.boundary
+                    .iter()
+                    .map(|corner| {
+                        *corner_indices.entry(*corner).or_insert_with(|| {
+                            positions.push(polyhedron.positions[*corner as usize].0);
+                            positions.len() - 1
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+        PolyMesh { positions, faces }
+    }
+}
+
+impl<S: BaseFloat> PolyMesh<S> {
+    /// One vertex per face and one face per vertex.
+    pub fn dual(&self) -> Result<PolyMesh<S>, PolyhedronError> {
+        let rings = self.vertex_rings()?;
+        let positions = self
+            .faces
+            .iter()
+            .map(|face| self.face_center(face))
+            .collect();
+        let faces = rings
+            .into_iter()
+            .map(|ring| ring.into_iter().map(|(face_index, _)| face_index).collect())
+            .collect();
+        Ok(PolyMesh { positions, faces })
+    }
+
+    /// A pyramid on every face: each n-gon becomes n triangles around a new
+    /// vertex at its center.
+    pub fn kis(&self) -> Result<PolyMesh<S>, PolyhedronError> {
+        self.check_vertices()?;
+        let mut positions = self.positions.clone();
+        let mut faces = vec![];
+        for face in &self.faces {
+            positions.push(self.face_center(face));
+            let center = positions.len() - 1;
+            for k in 0..face.len() {
+                faces.push(vec![face[k], face[(k + 1) % face.len()], center]);
+            }
+        }
+        Ok(PolyMesh { positions, faces })
+    }
+
+    /// One vertex per edge, at its midpoint; faces shrink to the midpoints of
+    /// their edges and every vertex becomes a face.
+    pub fn ambo(&self) -> Result<PolyMesh<S>, PolyhedronError> {
+        self.cut_corners(
+            |from, to| (from.min(to), from.max(to)),
+            |from, to| spherical_mean(&[self.positions[from], self.positions[to]]),
+        )
+    }
+
+    /// Cuts every vertex off a third of the way along its edges, turning
+    /// n-gons into 2n-gons and every vertex into a face.
+    pub fn truncate(&self) -> Result<PolyMesh<S>, PolyhedronError> {
+        self.cut_corners(
+            |from, to| (from, to),
+            |from, to| {
+                let (a, b) = (self.positions[from], self.positions[to]);
+                spherical_mean(&[a, a, b])
+            },
+        )
+    }
+
+    /// Replaces every edge with a hexagon, keeping the original vertices and
+    /// shrinking each face towards its center by a third.
+    pub fn chamfer(&self) -> Result<PolyMesh<S>, PolyhedronError> {
+        let edge_faces = self.edge_faces()?;
+        let mut positions = self.positions.clone();
+        let mut faces = vec![];
+        let mut inset = HashMap::new();
+        for (face_index, face) in self.faces.iter().enumerate() {
+            let center = self.face_center(face);
+            let mut inset_face = vec![];
+            for vertex in face {
+                positions.push(spherical_mean(&[
+                    self.positions[*vertex],
+                    self.positions[*vertex],
+                    center,
+                ]));
+                inset.insert((face_index, *vertex), positions.len() - 1);
+                inset_face.push(positions.len() - 1);
+            }
+            faces.push(inset_face);
+        }
+
+        for (face_index, face) in self.faces.iter().enumerate() {
+            for k in 0..face.len() {
+                let (a, b) = (face[k], face[(k + 1) % face.len()]);
+                if a > b {
+                    continue;
+                }
+                let other = edge_faces[&(b, a)];
+                faces.push(vec![
+                    a,
+                    inset[&(other, a)],
+                    inset[&(other, b)],
+                    b,
+                    inset[&(face_index, b)],
+                    inset[&(face_index, a)],
+                ]);
+            }
+        }
+        Ok(PolyMesh { positions, faces })
+    }
+
+    /// Triangulates every face as a fan around its center into a `Polyhedron`
+    /// with one tile per face, so the tile API works on any tiling.
+    pub fn to_polyhedron(&self) -> Result<Polyhedron<S>, PolyhedronError> {
+        let edge_faces = self.edge_faces()?;
+        let mut polyhedron = Polyhedron::new();
+        polyhedron.positions = self
+            .positions
+            .iter()
+            .map(|position| ArraySerializedVector(*position))
+            .collect();
+        for face in &self.faces {
+            polyhedron
+                .positions
+                .push(ArraySerializedVector(self.face_center(face)));
+            let center = polyhedron.positions.len() - 1;
+            let mut cells = vec![];
+            for k in 0..face.len() {
+                polyhedron
+                    .cells
+                    .push(Triangle::new(center, face[k], face[(k + 1) % face.len()]));
+                cells.push(polyhedron.cells.len() as u32 - 1);
+            }
+            polyhedron.faces.push_row(cells);
+            polyhedron.tile_centers.push(center as u32);
+            polyhedron
+                .tile_boundaries
+                .push_row(face.iter().map(|corner| *corner as u32));
+            polyhedron.tile_neighbours.push_row(
+                (0..face.len()).map(|k| edge_faces[&(face[(k + 1) % face.len()], face[k])] as u32),
+            );
+        }
+        for _ in 0..polyhedron.positions.len() {
+            polyhedron
+                .normals
+                .push(ArraySerializedVector(Vector3::zero()));
+            polyhedron
+                .colors
+                .push(ArraySerializedVector(Vector3::new(1.0, 1.0, 1.0)));
+        }
+        Ok(polyhedron)
+    }
+
+    /// Shared by ambo and truncate: every face keeps the new vertices on its
+    /// edges and every old vertex becomes a face of the new vertices around
+    /// it. `point(from, to)` places the new vertex on the edge from `from` to
+    /// `to` nearest `from`, and edges with the same `key` share it.
+    fn cut_corners(
+        &self,
+        key: impl Fn(usize, usize) -> (usize, usize),
+        point: impl Fn(usize, usize) -> Vector3<S>,
+    ) -> Result<PolyMesh<S>, PolyhedronError> {
+        let rings = self.vertex_rings()?;
+        let mut positions = vec![];
+        let mut indices = HashMap::new();
+        let mut index = |from: usize, to: usize| {
+            *indices.entry(key(from, to)).or_insert_with(|| {
+                positions.push(point(from, to));
+                positions.len() - 1
+            })
+        };
+
+        let mut faces = vec![];
+        for face in &self.faces {
+            let mut new_face = vec![];
+            for k in 0..face.len() {
+                let (a, b) = (face[k], face[(k + 1) % face.len()]);
+                new_face.extend([index(a, b), index(b, a)]);
+            }
+            new_face.dedup();
+            faces.push(new_face);
+        }
+        for (vertex, ring) in rings.into_iter().enumerate() {
+            faces.push(
+                ring.into_iter()
+                    .map(|(_, next)| index(vertex, next))
+                    .collect(),
+            );
+        }
+        Ok(PolyMesh { positions, faces })
+    }
+
+    /// Checks that every face refers to vertices the mesh has.
+    fn check_vertices(&self) -> Result<(), PolyhedronError> {
+        for (face_index, face) in self.faces.iter().enumerate() {
+            if let Some(vertex) = face.iter().find(|vertex| **vertex >= self.positions.len()) {
+                return Err(PolyhedronError::VertexOutOfRange {
+                    face: face_index,
+                    vertex: *vertex,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// For every directed edge, the face it belongs to.
+    fn edge_faces(&self) -> Result<HashMap<(usize, usize), usize>, PolyhedronError> {
+        self.check_vertices()?;
+        let mut edge_faces = HashMap::new();
+        for (face_index, face) in self.faces.iter().enumerate() {
+            for k in 0..face.len() {
+                let (a, b) = (face[k], face[(k + 1) % face.len()]);
+                if a == b || edge_faces.insert((a, b), face_index).is_some() {
+                    return Err(PolyhedronError::NonManifoldEdge {
+                        a: a.min(b),
+                        b: a.max(b),
+                    });
+                }
+            }
+        }
+        for face in &self.faces {
+            for k in 0..face.len() {
+                let (a, b) = (face[k], face[(k + 1) % face.len()]);
+                if !edge_faces.contains_key(&(b, a)) {
+                    return Err(PolyhedronError::NonManifoldEdge {
+                        a: a.min(b),
+                        b: a.max(b),
+                    });
+                }
+            }
+        }
+        Ok(edge_faces)
+    }
+
+    /// For every vertex, the faces around it counter-clockwise seen from
+    /// outside, each with the vertex that follows it in that face.
+    fn vertex_rings(&self) -> Result<Vec<Vec<(usize, usize)>>, PolyhedronError> {
+        let edge_faces = self.edge_faces()?;
+        let mut first_face = vec![None; self.positions.len()];
+        let mut face_counts = vec![0; self.positions.len()];
+        for (face_index, face) in self.faces.iter().enumerate() {
+            for vertex in face {
+                first_face[*vertex].get_or_insert(face_index);
+                face_counts[*vertex] += 1;
+            }
+        }
+
+        first_face
+            .iter()
+            .enumerate()
+            .map(|(vertex, first)| {
+                let first = first.ok_or(PolyhedronError::IsolatedVertex(vertex))?;
+                let mut ring = vec![];
+                let mut face_index = first;
+                loop {
+                    let face = &self.faces[face_index];
+                    let k = face.iter().position(|v| *v == vertex).unwrap();
+                    let next = face[(k + 1) % face.len()];
+                    let previous = face[(k + face.len() - 1) % face.len()];
+                    ring.push((face_index, next));
+                    // The face across the edge into `vertex` comes next
+                    // counter-clockwise.
+                    face_index = edge_faces[&(vertex, previous)];
+                    if face_index == first {
+                        break;
+                    }
+                }
+                // A pinched vertex has faces left over in another ring.
+                if ring.len() != face_counts[vertex] {
+                    return Err(PolyhedronError::MissingAdjacentFace {
+                        vertex,
+                        face: ring[ring.len() - 1].0,
+                    });
+                }
+                Ok(ring)
+            })
+            .collect()
+    }
+
+    fn face_center(&self, face: &[usize]) -> Vector3<S> {
+        let corners: Vec<Vector3<S>> = face.iter().map(|vertex| self.positions[*vertex]).collect();
+        spherical_mean(&corners)
+    }
+}
+
+/// Mean of `points` moved out to their mean distance from the origin.
+fn spherical_mean<S: BaseFloat>(points: &[Vector3<S>]) -> Vector3<S> {
+    let count = scalar::<S>(points.len() as f64);
+    let sum = points
+        .iter()
+        .fold(Vector3::zero(), |sum, point| sum + *point);
+    let radius = points
+        .iter()
+        .fold(S::zero(), |sum, point| sum + point.magnitude())
+        / count;
+    if sum.magnitude2() <= S::zero() {
+        return sum / count;
+    }
+    sum.normalize() * radius
+}
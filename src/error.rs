@@ -0,0 +1,52 @@
+use std::error::Error;
+use std::fmt;
+
+/// Why a polyhedron could not be generated or transformed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolyhedronError {
+    /// `2^detail` does not fit in a `u32` frequency.
+    InvalidDetail(u32),
+    /// GP(0, 0), or a GP(m, n) subdivision whose truncation has more
+    /// triangle corners than `u32` indices can address.
+    InvalidFrequency { m: u32, n: u32 },
+    /// A face refers to a vertex that does not exist.
+    VertexOutOfRange { face: usize, vertex: usize },
+    /// A vertex that no face uses.
+    IsolatedVertex(usize),
+    /// The edge between two vertices is not shared by exactly two faces, one
+    /// running each way.
+    NonManifoldEdge { a: usize, b: usize },
+    /// The faces around a vertex do not close into a single ring, as around
+    /// a pinched vertex.
+    MissingAdjacentFace { vertex: usize, face: usize },
+}
+
+impl fmt::Display for PolyhedronError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolyhedronError::InvalidDetail(detail) => {
+                write!(f, "detail {detail} is too large")
+            }
+            PolyhedronError::InvalidFrequency { m, n } => {
+                write!(f, "GP({m}, {n}) is not a usable subdivision")
+            }
+            PolyhedronError::VertexOutOfRange { face, vertex } => {
+                write!(f, "face {face} refers to missing vertex {vertex}")
+            }
+            PolyhedronError::IsolatedVertex(vertex) => {
+                write!(f, "vertex {vertex} is not used by any face")
+            }
+            PolyhedronError::NonManifoldEdge { a, b } => {
+                write!(f, "edge {a}-{b} is not shared by exactly two faces")
+            }
+            PolyhedronError::MissingAdjacentFace { vertex, face } => {
+                write!(
+                    f,
+                    "no face continues the ring around vertex {vertex} after face {face}"
+                )
+            }
+        }
+    }
+}
+
+impl Error for PolyhedronError {}
@@ -0,0 +1,212 @@
+use std::io::{self, Write};
+
+use cgmath::prelude::*;
+use cgmath::BaseFloat;
+use serde_json::json;
+
+use crate::{to_f32, Polyhedron};
+
+const GLB_MAGIC: &[u8; 4] = b"glTF";
+const GLB_JSON_CHUNK: u32 = 0x4E4F_534A;
+const GLB_BIN_CHUNK: u32 = 0x004E_4942;
+const GL_FLOAT: u32 = 5126;
+const GL_UNSIGNED_INT: u32 = 5125;
+const GL_ARRAY_BUFFER: u32 = 34962;
+const GL_ELEMENT_ARRAY_BUFFER: u32 = 34963;
+
+impl<S: BaseFloat> Polyhedron<S> {
+    /// Writes a Wavefront OBJ. Truncated polyhedra get one group per tile,
+    /// named `tile_<index>`. Normals are only written once computed with
+    /// `compute_face_normals` or `compute_triangle_normals`.
+    pub fn write_obj<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writeln!(writer, "o polyhedron")?;
+        for position in &self.positions {
+            let [x, y, z] = to_f64(position.0);
+            writeln!(writer, "v {x} {y} {z}")?;
+        }
+        let has_normals = self.has_normals();
+        if has_normals {
+            for normal in &self.normals {
+                let [x, y, z] = to_f64(normal.0);
+                writeln!(writer, "vn {x} {y} {z}")?;
+            }
+        }
+
+        let write_cell = |writer: &mut W, cell_index: usize| {
+            let cell = &self.cells[cell_index];
+            let [a, b, c] = [cell.a + 1, cell.b + 1, cell.c + 1];
+            if has_normals {
+                writeln!(writer, "f {a}//{a} {b}//{b} {c}//{c}")
+            } else {
+                writeln!(writer, "f {a} {b} {c}")
+            }
+        };
+        if self.tile_count() == 0 {
+            for cell_index in 0..self.cells.len() {
+                write_cell(writer, cell_index)?;
+            }
+        } else {
+            for (tile_index, cells) in self.faces.iter().enumerate() {
+                writeln!(writer, "g tile_{tile_index}")?;
+                for cell_index in cells {
+                    write_cell(writer, *cell_index as usize)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes an ASCII PLY with per-vertex colours, plus normals once computed.
+    pub fn write_ply<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let has_normals = self.has_normals();
+        writeln!(writer, "ply")?;
+        writeln!(writer, "format ascii 1.0")?;
+        writeln!(writer, "element vertex {}", self.positions.len())?;
+        for axis in ["x", "y", "z"] {
+            writeln!(writer, "property double {axis}")?;
+        }
+        if has_normals {
+            for axis in ["nx", "ny", "nz"] {
+                writeln!(writer, "property double {axis}")?;
+            }
+        }
+        for channel in ["red", "green", "blue"] {
+            writeln!(writer, "property uchar {channel}")?;
+        }
+        writeln!(writer, "element face {}", self.cells.len())?;
+        writeln!(writer, "property list uchar uint vertex_indices")?;
+        writeln!(writer, "end_header")?;
+
+        for (vertex_index, position) in self.positions.iter().enumerate() {
+            let [x, y, z] = to_f64(position.0);
+            write!(writer, "{x} {y} {z}")?;
+            if has_normals {
+                let [nx, ny, nz] = to_f64(self.normals[vertex_index].0);
+                write!(writer, " {nx} {ny} {nz}")?;
+            }
+            let color = self.colors[vertex_index].0;
+            let [r, g, b] = [color.x, color.y, color.z]
+                .map(|channel| (channel.clamp(0.0, 1.0) * 255.0).round() as u8);
+            writeln!(writer, " {r} {g} {b}")?;
+        }
+        for cell in &self.cells {
+            writeln!(writer, "3 {} {} {}", cell.a, cell.b, cell.c)?;
+        }
+        Ok(())
+    }
+
+    /// Writes a binary glTF 2.0 (GLB) holding a single mesh with positions,
+    /// vertex colours and, once computed, normals. glTF has no empty
+    /// accessors, so a polyhedron without triangles is an `InvalidInput`
+    /// error.
+    pub fn write_glb<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        if self.positions.is_empty() || self.cells.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "a GLB mesh needs at least one triangle",
+            ));
+        }
+        let positions: Vec<[f32; 3]> = self.positions.iter().map(|p| to_f32(p.0)).collect();
+        let normals: Vec<[f32; 3]> = self.normals.iter().map(|n| to_f32(n.0)).collect();
+        let colors: Vec<[f32; 3]> = self.colors.iter().map(|c| [c.0.x, c.0.y, c.0.z]).collect();
+        let indices: Vec<u32> = self.export_cells();
+
+        let mut min = [f32::INFINITY; 3];
+        let mut max = [f32::NEG_INFINITY; 3];
+        for position in &positions {
+            for axis in 0..3 {
+                min[axis] = min[axis].min(position[axis]);
+                max[axis] = max[axis].max(position[axis]);
+            }
+        }
+
+        let mut bin = vec![];
+        let mut buffer_views = vec![];
+        let mut accessors = vec![];
+        let mut attributes = serde_json::Map::new();
+        let mut vertex_attributes = vec![("POSITION", &positions), ("COLOR_0", &colors)];
+        if self.has_normals() {
+            vertex_attributes.push(("NORMAL", &normals));
+        }
+        for (name, values) in vertex_attributes {
+            let offset = bin.len();
+            bin.extend(
+                values
+                    .iter()
+                    .flatten()
+                    .flat_map(|value| value.to_le_bytes()),
+            );
+            let mut accessor = json!({
+                "bufferView": buffer_views.len(),
+                "componentType": GL_FLOAT,
+                "count": values.len(),
+                "type": "VEC3",
+            });
+            if name == "POSITION" {
+                accessor["min"] = json!(min);
+                accessor["max"] = json!(max);
+            }
+            buffer_views.push(json!({
+                "buffer": 0,
+                "byteOffset": offset,
+                "byteLength": bin.len() - offset,
+                "target": GL_ARRAY_BUFFER,
+            }));
+            attributes.insert(name.to_string(), json!(accessors.len()));
+            accessors.push(accessor);
+        }
+        let offset = bin.len();
+        bin.extend(indices.iter().flat_map(|index| index.to_le_bytes()));
+        buffer_views.push(json!({
+            "buffer": 0,
+            "byteOffset": offset,
+            "byteLength": bin.len() - offset,
+            "target": GL_ELEMENT_ARRAY_BUFFER,
+        }));
+        accessors.push(json!({
+            "bufferView": buffer_views.len() - 1,
+            "componentType": GL_UNSIGNED_INT,
+            "count": indices.len(),
+            "type": "SCALAR",
+        }));
+
+        let document = json!({
+            "asset": { "version": "2.0", "generator": "web_hex" },
+            "scene": 0,
+            "scenes": [{ "nodes": [0] }],
+            "nodes": [{ "mesh": 0, "name": "polyhedron" }],
+            "meshes": [{
+                "primitives": [{ "attributes": attributes, "indices": accessors.len() - 1 }],
+            }],
+            "buffers": [{ "byteLength": bin.len() }],
+            "bufferViews": buffer_views,
+            "accessors": accessors,
+        });
+        let mut json = serde_json::to_vec(&document)?;
+
+        // Both chunks must be 4-byte aligned: JSON is padded with spaces and
+        // the binary chunk with zeros.
+        json.resize(json.len().next_multiple_of(4), b' ');
+        bin.resize(bin.len().next_multiple_of(4), 0);
+        let total_length = 12 + 8 + json.len() + 8 + bin.len();
+
+        writer.write_all(GLB_MAGIC)?;
+        writer.write_all(&2u32.to_le_bytes())?;
+        writer.write_all(&(total_length as u32).to_le_bytes())?;
+        writer.write_all(&(json.len() as u32).to_le_bytes())?;
+        writer.write_all(&GLB_JSON_CHUNK.to_le_bytes())?;
+        writer.write_all(&json)?;
+        writer.write_all(&(bin.len() as u32).to_le_bytes())?;
+        writer.write_all(&GLB_BIN_CHUNK.to_le_bytes())?;
+        writer.write_all(&bin)
+    }
+
+    /// Whether normals have been computed for every vertex.
+    fn has_normals(&self) -> bool {
+        !self.normals.is_empty() && self.normals.iter().all(|n| n.0.magnitude2() > S::zero())
+    }
+}
+
+fn to_f64<S: BaseFloat>(vector: cgmath::Vector3<S>) -> [f64; 3] {
+    vector.cast::<f64>().unwrap().into()
+}
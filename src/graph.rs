@@ -0,0 +1,86 @@
+use std::collections::VecDeque;
+
+use cgmath::prelude::*;
+use cgmath::BaseFloat;
+
+use crate::Polyhedron;
+
+/// Breadth-first walk over the tile graph, yielding `(tile, hops)` in order
+/// of increasing hop count. Created by `Polyhedron::hops_from`.
+#[derive(Debug, Clone)]
+pub struct Hops<'a, S = f32> {
+    polyhedron: &'a Polyhedron<S>,
+    max_hops: usize,
+    visited: Vec<bool>,
+    queue: VecDeque<(usize, usize)>,
+}
+
+impl<'a, S: BaseFloat> Iterator for Hops<'a, S> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        let (tile_index, hops) = self.queue.pop_front()?;
+        if hops < self.max_hops {
+            for neighbour in &self.polyhedron.tile_neighbours()[tile_index] {
+                let neighbour = *neighbour as usize;
+                if !self.visited[neighbour] {
+                    self.visited[neighbour] = true;
+                    self.queue.push_back((neighbour, hops + 1));
+                }
+            }
+        }
+        Some((tile_index, hops))
+    }
+}
+
+impl<S: BaseFloat> Polyhedron<S> {
+    /// Great-circle distance between two tile centers over the sphere the
+    /// polyhedron was generated on. `None` if either is not a tile.
+    pub fn tile_distance(&self, from: usize, to: usize) -> Option<S> {
+        if from >= self.tile_count() || to >= self.tile_count() {
+            return None;
+        }
+        Some(self.tile_center(from).angle(self.tile_center(to)).0 * self.radius())
+    }
+
+    /// Smallest number of steps between neighbouring tiles that leads from
+    /// `from` to `to`. `None` if either is not a tile or `to` cannot be
+    /// reached.
+    pub fn hop_distance(&self, from: usize, to: usize) -> Option<usize> {
+        if to >= self.tile_count() {
+            return None;
+        }
+        self.hops_from(from, usize::MAX)
+            .find(|(tile_index, _)| *tile_index == to)
+            .map(|(_, hops)| hops)
+    }
+
+    /// Every tile at most `max_hops` steps from `center`, with its hop count,
+    /// nearest first. Empty if `center` is not a tile.
+    pub fn hops_from(&self, center: usize, max_hops: usize) -> Hops<'_, S> {
+        let mut visited = vec![false; self.tile_count()];
+        let mut queue = VecDeque::new();
+        if let Some(seen) = visited.get_mut(center) {
+            *seen = true;
+            queue.push_back((center, 0));
+        }
+        Hops {
+            polyhedron: self,
+            max_hops,
+            visited,
+            queue,
+        }
+    }
+
+    /// Tiles within `k` hops of `center`, including `center` itself.
+    pub fn tiles_within(&self, center: usize, k: usize) -> impl Iterator<Item = usize> + '_ {
+        self.hops_from(center, k).map(|(tile_index, _)| tile_index)
+    }
+
+    /// Tiles exactly `k` hops from `center`.
+    pub fn tile_ring(&self, center: usize, k: usize) -> impl Iterator<Item = usize> + '_ {
+        self.hops_from(center, k)
+            .skip_while(move |(_, hops)| *hops < k)
+            .map(|(tile_index, _)| tile_index)
+    }
+}
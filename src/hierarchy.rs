@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+use cgmath::BaseFloat;
+
+use crate::{lattice_point_address, Adjacency, Lattice, Polyhedron, TileAddress};
+
+/// Parent/child links between the tiles of a coarse and a fine polyhedron of
+/// the same sphere, e.g. `new_truncated_isocahedron` at detail `d` and
+/// `d + 1`. Every fine tile has the coarse tile containing its center as its
+/// parent.
+///
+/// When the fine lattice is GP(2m, 2n) over a coarse GP(m, n) of the same
+/// base solid, as between consecutive details, parents come from tile
+/// addresses. Every coarse tile keeps the fine tile at its center, and the
+/// fine tiles centered on the edges between coarse tiles are shared out so
+/// that each coarse tile gets half of its edges, rounded either way: four
+/// children per hexagon, and three or four per pentagon. Otherwise parents
+/// are found by locating fine tile centers among the coarse tiles.
+#[derive(Debug, Clone)]
+pub struct TileHierarchy {
+    parents: Vec<usize>,
+    children: Adjacency,
+}
+
+impl TileHierarchy {
+    pub fn new<S: BaseFloat>(coarse: &Polyhedron<S>, fine: &Polyhedron<S>) -> TileHierarchy {
+        let parents: Vec<usize> = match halved_lattice(coarse, fine) {
+            Some(lattice) => {
+                let owners = balanced_owners(coarse.tile_neighbours());
+                (0..fine.tiles().len())
+                    .map(|tile_index| {
+                        let address = fine.tile_address(tile_index).unwrap();
+                        let [first, second] = parent_addresses(&lattice, address)
+                            .map(|parent| coarse.tile_at(parent).unwrap());
+                        owners[&(first.min(second), first.max(second))]
+                    })
+                    .collect()
+            }
+            None => {
+                let locator = coarse.tile_locator();
+                (0..fine.tiles().len())
+                    .map(|tile_index| locator.locate(fine.tile_center(tile_index)).unwrap())
+                    .collect()
+            }
+        };
+        let children = Adjacency::from_pairs(
+            coarse.tile_count(),
+            parents
+                .iter()
+                .enumerate()
+                .map(|(tile_index, parent)| (*parent, tile_index)),
+        );
+        TileHierarchy { parents, children }
+    }
+
+    /// Coarse tile containing the fine tile `tile_index`.
+    pub fn parent(&self, tile_index: usize) -> usize {
+        self.parents[tile_index]
+    }
+
+    /// Fine tiles whose parent is the coarse tile `tile_index`.
+    pub fn children(&self, tile_index: usize) -> &[u32] {
+        &self.children[tile_index]
+    }
+
+    /// Combines the values of each coarse tile's children, e.g. to total or
+    /// average fine-grid statistics per coarse tile.
+    pub fn aggregate<T, R>(
+        &self,
+        fine_values: &[T],
+        mut combine: impl FnMut(&[&T]) -> R,
+    ) -> Vec<R> {
+        self.children
+            .iter()
+            .map(|children| {
+                let values: Vec<&T> = children
+                    .iter()
+                    .map(|child| &fine_values[*child as usize])
+                    .collect();
+                combine(&values)
+            })
+            .collect()
+    }
+
+    /// Hands every fine tile its parent's value, e.g. to render a coarse
+    /// simulation on the fine grid.
+    pub fn refine<T: Clone>(&self, coarse_values: &[T]) -> Vec<T> {
+        self.parents
+            .iter()
+            .map(|parent| coarse_values[*parent].clone())
+            .collect()
+    }
+}
+
+/// The coarse lattice, if the fine one is GP(2m, 2n) on the same base solid
+/// and both polyhedra are truncated.
+fn halved_lattice<S: BaseFloat>(
+    coarse: &Polyhedron<S>,
+    fine: &Polyhedron<S>,
+) -> Option<Lattice<S>> {
+    let (coarse_lattice, fine_lattice) = (coarse.lattice?, fine.lattice?);
+    let halved = fine_lattice.base == coarse_lattice.base
+        && fine_lattice.m == 2 * coarse_lattice.m
+        && fine_lattice.n == 2 * coarse_lattice.n
+        && coarse.tile_count() > 0
+        && fine.tile_count() > 0;
+    halved.then_some(coarse_lattice)
+}
+
+/// The coarse tiles a fine tile could belong to. Fine lattice point (i, j)
+/// sits at (i, j) / 2 on the coarse lattice: on a coarse point when both are
+/// even, twice over, and otherwise halfway between two neighbouring ones.
+fn parent_addresses<S>(lattice: &Lattice<S>, address: TileAddress) -> [TileAddress; 2] {
+    let TileAddress::Face { face, i, j } = address else {
+        return [address; 2];
+    };
+    let (di, dj) = match (i.rem_euclid(2), j.rem_euclid(2)) {
+        (0, 0) => (0, 0),
+        (1, 0) => (1, 0),
+        (0, 1) => (0, 1),
+        _ => (1, -1),
+    };
+    [(i - di, j - dj), (i + di, j + dj)]
+        .map(|(i, j)| lattice_point_address(lattice, face, i / 2, j / 2))
+}
+
+/// Picks one end of every edge between tiles, and each tile as the owner of
+/// itself, so that every tile owns half of its edges give or take one.
+/// Pairing up the tiles with an odd number of edges makes every count even,
+/// and walking closed trails then leaves every tile as often as it enters
+/// it; each edge goes to the tile the walk leaves.
+fn balanced_owners(neighbours: &Adjacency) -> HashMap<(usize, usize), usize> {
+    let mut edges: Vec<(usize, usize)> = neighbours
+        .iter()
+        .enumerate()
+        .flat_map(|(tile, row)| row.iter().map(move |other| (tile, *other as usize)))
+        .filter(|(tile, other)| tile < other)
+        .collect();
+    let real_edges = edges.len();
+    let odd: Vec<usize> = (0..neighbours.len())
+        .filter(|tile| neighbours[*tile].len() % 2 == 1)
+        .collect();
+    edges.extend(odd.chunks_exact(2).map(|pair| (pair[0], pair[1])));
+
+    let tile_edges = Adjacency::from_pairs(
+        neighbours.len(),
+        edges
+            .iter()
+            .enumerate()
+            .flat_map(|(edge, (a, b))| [(*a, edge), (*b, edge)]),
+    );
+    let mut next_edge = vec![0; neighbours.len()];
+    let mut used = vec![false; edges.len()];
+    let mut owners: HashMap<(usize, usize), usize> = (0..neighbours.len())
+        .map(|tile| ((tile, tile), tile))
+        .collect();
+    for start in 0..neighbours.len() {
+        let mut current = start;
+        loop {
+            let row = &tile_edges[current];
+            while next_edge[current] < row.len() && used[row[next_edge[current]] as usize] {
+                next_edge[current] += 1;
+            }
+            let Some(&edge) = row.get(next_edge[current]) else {
+                break;
+            };
+            let edge = edge as usize;
+            used[edge] = true;
+            let (a, b) = edges[edge];
+            if edge < real_edges {
+                owners.insert((a, b), current);
+            }
+            current = if current == a { b } else { a };
+        }
+    }
+    owners
+}
@@ -3,25 +3,66 @@ extern crate rand;
 
 use std::collections::HashMap;
 use std::ops::AddAssign;
+use std::sync::OnceLock;
+use std::time::Instant;
 
 use cgmath::prelude::*;
-use cgmath::Vector3;
+use cgmath::{BaseFloat, Vector3};
 use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
 use serde::ser::{SerializeSeq, Serializer};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
-const VERT_CACHE_PRECISION: f32 = 10000_f32;
+mod adjacency;
+mod binary;
+mod conway;
+mod error;
+mod export;
+mod graph;
+mod hierarchy;
+mod mesh;
+mod metrics;
+mod numbering;
+mod parallel;
+mod projection;
+mod relax;
+mod serialization;
+mod stats;
+mod tiles;
+mod validation;
 
-#[derive(Debug)]
+pub use adjacency::Adjacency;
+pub use conway::PolyMesh;
+pub use error::PolyhedronError;
+pub use graph::Hops;
+pub use hierarchy::TileHierarchy;
+pub use metrics::{MetricSummary, MetricsReport, TileMetrics};
+pub use projection::Projection;
+pub use stats::GenerationStats;
+pub use tiles::{Point, Tile, Tiles};
+pub use validation::ValidationIssue;
+
+use numbering::Numbering;
+
+#[derive(Debug, Clone)]
 pub struct Triangle {
-    pub a: usize,
-    pub b: usize,
-    pub c: usize,
+    pub a: u32,
+    pub b: u32,
+    pub c: u32,
 }
 
 impl Triangle {
     fn new(a: usize, b: usize, c: usize) -> Triangle {
-        Triangle { a, b, c }
+        Triangle {
+            a: a as u32,
+            b: b as u32,
+            c: c as u32,
+        }
+    }
+
+    /// The three vertex indices, for indexing `positions`.
+    pub fn corners(&self) -> [usize; 3] {
+        [self.a as usize, self.b as usize, self.c as usize]
     }
 }
 
@@ -39,26 +80,201 @@ impl Serialize for Triangle {
     }
 }
 
-#[derive(Debug, Deserialize)]
-pub struct ArraySerializedVector(pub Vector3<f32>);
+impl<'de> Deserialize<'de> for Triangle {
+    fn deserialize<D>(deserializer: D) -> Result<Triangle, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let [a, b, c] = <[u32; 3]>::deserialize(deserializer)?;
+        Ok(Triangle { a, b, c })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ArraySerializedVector<T = f32>(pub Vector3<T>);
 
-#[derive(Serialize, Debug)]
-pub struct Polyhedron {
-    pub positions: Vec<ArraySerializedVector>,
+/// A polyhedron whose positions and normals use the scalar `S`. `f32` is
+/// plenty for rendering; `f64` keeps centroids and normals accurate at high
+/// detail and planet-scale radii, and `cast` turns it back into `f32`.
+///
+/// Serializes to JSON with its positions, triangles, normals, colours and
+/// per-tile triangles, plus the tiles and base lattice when it has them,
+/// and deserializes back into a polyhedron that works like the one saved,
+/// with tile addresses worked out from the lattice.
+#[derive(Debug, Clone)]
+pub struct Polyhedron<S = f32> {
+    pub positions: Vec<ArraySerializedVector<S>>,
     pub cells: Vec<Triangle>,
-    pub normals: Vec<ArraySerializedVector>,
+    pub normals: Vec<ArraySerializedVector<S>>,
     pub colors: Vec<ArraySerializedVector>,
-    #[serde(skip)]
-    added_vert_cache: HashMap<(i32, i32, i32), usize>,
-    faces: Vec<Vec<usize>>,
+    faces: Adjacency,
+    tile_centers: Vec<u32>,
+    tile_boundaries: Adjacency,
+    tile_neighbours: Adjacency,
+    lattice: Option<Lattice<S>>,
+    numbering: Option<Numbering>,
+    stats: GenerationStats,
+}
+
+/// Identifies a vertex by where it sits in the construction rather than by
+/// its coordinates, so vertices shared between triangles merge exactly at
+/// any radius and detail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum VertexKey {
+    /// A vertex of the base solid.
+    Corner(usize),
+    /// `step` lattice steps along the base edge from the lower to the higher
+    /// numbered vertex.
+    Edge(usize, usize, i64),
+    /// A lattice point strictly inside a base triangle, by its scaled weights
+    /// on the triangle's second and third corner.
+    Face(usize, i64, i64),
+}
+
+/// One tile of a truncation, worked out before any of its vertices are
+/// numbered: per boundary corner, the untruncated triangle it is the
+/// centroid of and the midpoints towards the previous and next neighbour.
+struct TilePlan<S> {
+    center: Vector3<S>,
+    neighbours: Vec<usize>,
+    corners: Vec<(usize, EdgePoint<S>, EdgePoint<S>)>,
+}
+
+/// Midpoint between the centroids of two untruncated triangles, with the
+/// lower numbered triangle and which of its edges they share, by opposite
+/// corner.
+type EdgePoint<S> = ((usize, usize), Vector3<S>);
+
+/// Indices already given to the vertices that tiles share while truncating,
+/// `UNNUMBERED` until then: a centroid per untruncated triangle and an edge
+/// midpoint per triangle edge, stored with the lower numbered triangle.
+struct SharedVertices {
+    centroids: Vec<usize>,
+    mid_centroids: Vec<[usize; 3]>,
+}
+
+const UNNUMBERED: usize = usize::MAX;
+
+/// The neighbour and far vertex across the edge opposite each corner of
+/// every triangle, as found by `opposite_faces`.
+type OppositeFaces = Vec<[(usize, usize); 3]>;
+
+/// Parameters shared by every base triangle while subdividing into GP(m, n).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct Lattice<S> {
+    base: BaseSolid,
+    m: i64,
+    n: i64,
+    radius: S,
+    projection: Projection,
+}
+
+impl<S> Lattice<S> {
+    /// Triangulation number: each base triangle holds `t` lattice triangles.
+    fn t(&self) -> i64 {
+        self.m * self.m + self.m * self.n + self.n * self.n
+    }
+
+    /// Weight change between consecutive lattice points on a base edge.
+    fn edge_step(&self) -> i64 {
+        self.t() / gcd(self.m, self.n)
+    }
+
+    /// Barycentric weights of the lattice point (i, j) / scale, scaled by
+    /// `scale * t`.
+    fn weights(&self, i: i64, j: i64, scale: i64) -> [i64; 3] {
+        let (m, n) = (self.m, self.n);
+        let u = (m + n) * i + n * j;
+        let v = m * j - n * i;
+        [scale * self.t() - u - v, u, v]
+    }
+
+    /// Inverse of `weights` for whole lattice points: (i, j) from the weights
+    /// on a triangle's second and third corner.
+    fn coordinates(&self, u: i64, v: i64) -> (i64, i64) {
+        let (m, n) = (self.m, self.n);
+        ((m * u - n * v) / self.t(), (n * u + (m + n) * v) / self.t())
+    }
+}
+
+/// Triangulated solid that geodesic spheres are subdivided from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BaseSolid {
+    /// 12 pentagons among the hexagons; the most even tiling.
+    #[default]
+    Icosahedron,
+    /// 6 squares among the hexagons, two of them on the poles.
+    Octahedron,
+    /// 4 triangles among the hexagons.
+    Tetrahedron,
+}
+
+impl BaseSolid {
+    /// Every base solid, in declaration order.
+    pub const ALL: [BaseSolid; 3] = [
+        BaseSolid::Icosahedron,
+        BaseSolid::Octahedron,
+        BaseSolid::Tetrahedron,
+    ];
+}
+
+/// Where a tile sits on the base solid, independent of the order in which
+/// tiles were generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TileAddress {
+    /// A pentagon (or square or triangle on other base solids), at a vertex of
+    /// the base solid.
+    Vertex(usize),
+    /// A hexagon at lattice point (i, j) of a base triangle, where the
+    /// triangle's corners are at (0, 0), (m, n) and (-n, m + n). Hexagons on a
+    /// base edge belong to the lower numbered triangle.
+    Face { face: usize, i: i64, j: i64 },
+}
+
+/// A hexagon or pentagon of a truncated polyhedron, borrowed from the
+/// polyhedron's flat tile arrays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct TileTopology<'a> {
+    /// Index of the tile's center vertex in `positions`.
+    pub center: u32,
+    /// Corner vertices in `positions`, counter-clockwise seen from outside.
+    pub boundary: &'a [u32],
+    /// Neighbouring tiles; `neighbours[k]` shares the edge from `boundary[k]`
+    /// to `boundary[k + 1]`.
+    pub neighbours: &'a [u32],
 }
 
-impl Serialize for ArraySerializedVector {
+impl TileTopology<'_> {
+    pub fn is_hex(&self) -> bool {
+        self.boundary.len() == 6
+    }
+}
+
+/// Finds the tile under a direction from the sphere's center, for picking
+/// and for importing lat/lon data. Built once with `Polyhedron::tile_locator`;
+/// each lookup jumps to a seed tile through a cube-map grid and then walks a
+/// few neighbours, so it costs the same on 40k tiles as on 92.
+#[derive(Debug, Clone)]
+pub struct TileLocator<S = f32> {
+    /// Unit tile centers.
+    centers: Vec<Vector3<S>>,
+    /// Unit vertex positions, for the tile corners.
+    points: Vec<Vector3<S>>,
+    /// Corners of each tile in `points`, counter-clockwise seen from outside.
+    boundaries: Adjacency,
+    neighbours: Adjacency,
+    /// Cells per cube face edge.
+    resolution: usize,
+    /// Nearest tile to the middle of every cube-map cell.
+    seeds: Vec<usize>,
+}
+
+impl<T: Serialize> Serialize for ArraySerializedVector<T> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let values = vec![self.0.x, self.0.y, self.0.z];
+        let values = vec![&self.0.x, &self.0.y, &self.0.z];
         let mut seq = serializer.serialize_seq(Some(values.len()))?;
         for value in values {
             seq.serialize_element(&value)?;
@@ -67,242 +283,919 @@ impl Serialize for ArraySerializedVector {
     }
 }
 
-impl AddAssign for ArraySerializedVector {
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for ArraySerializedVector<T> {
+    fn deserialize<D>(deserializer: D) -> Result<ArraySerializedVector<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let [x, y, z] = <[T; 3]>::deserialize(deserializer)?;
+        Ok(ArraySerializedVector(Vector3::new(x, y, z)))
+    }
+}
+
+impl<T: BaseFloat> AddAssign for ArraySerializedVector<T> {
     fn add_assign(&mut self, other: Self) {
         *self = Self(self.0 + other.0);
     }
 }
 
-impl Polyhedron {
-    pub fn new() -> Polyhedron {
+impl<S: BaseFloat> Default for Polyhedron<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: BaseFloat> Polyhedron<S> {
+    pub fn new() -> Polyhedron<S> {
         Polyhedron {
             positions: vec![],
             cells: vec![],
             normals: vec![],
             colors: vec![],
-            added_vert_cache: HashMap::new(),
-            faces: vec![],
+            faces: Adjacency::new(),
+            tile_centers: vec![],
+            tile_boundaries: Adjacency::new(),
+            tile_neighbours: Adjacency::new(),
+            lattice: None,
+            numbering: None,
+            stats: GenerationStats::default(),
         }
     }
 
-    pub fn new_isocahedron(radius: f32, detail: u32) -> Polyhedron {
-        let t = (1.0 + (5.0 as f32).sqrt()) / 2.0;
-        let mut base_isocahedron = Polyhedron {
-            positions: vec![],
-            cells: vec![
-                Triangle::new(0, 11, 5),
-                Triangle::new(0, 5, 1),
-                Triangle::new(0, 1, 7),
-                Triangle::new(0, 7, 10),
-                Triangle::new(0, 10, 11),
-                Triangle::new(1, 5, 9),
-                Triangle::new(5, 11, 4),
-                Triangle::new(11, 10, 2),
-                Triangle::new(10, 7, 6),
-                Triangle::new(7, 1, 8),
-                Triangle::new(3, 9, 4),
-                Triangle::new(3, 4, 2),
-                Triangle::new(3, 2, 6),
-                Triangle::new(3, 6, 8),
-                Triangle::new(3, 8, 9),
-                Triangle::new(4, 9, 5),
-                Triangle::new(2, 4, 11),
-                Triangle::new(6, 2, 10),
-                Triangle::new(8, 6, 7),
-                Triangle::new(9, 8, 1),
-            ],
-            normals: vec![],
-            colors: vec![],
-            added_vert_cache: HashMap::new(),
-            faces: vec![],
-        };
-        base_isocahedron.add_position(Vector3::new(-1.0, t, 0.0));
-        base_isocahedron.add_position(Vector3::new(1.0, t, 0.0));
-        base_isocahedron.add_position(Vector3::new(-1.0, -t, 0.0));
-        base_isocahedron.add_position(Vector3::new(1.0, -t, 0.0));
-        base_isocahedron.add_position(Vector3::new(0.0, -1.0, t));
-        base_isocahedron.add_position(Vector3::new(0.0, 1.0, t));
-        base_isocahedron.add_position(Vector3::new(0.0, -1.0, -t));
-        base_isocahedron.add_position(Vector3::new(0.0, 1.0, -t));
-        base_isocahedron.add_position(Vector3::new(t, 0.0, -1.0));
-        base_isocahedron.add_position(Vector3::new(t, 0.0, 1.0));
-        base_isocahedron.add_position(Vector3::new(-t, 0.0, -1.0));
-        base_isocahedron.add_position(Vector3::new(-t, 0.0, 1.0));
+    /// Geodesic icosahedron whose edges are split into `2^detail` segments.
+    pub fn new_isocahedron(radius: S, detail: u32) -> Result<Polyhedron<S>, PolyhedronError> {
+        Polyhedron::new_goldberg_isocahedron(radius, frequency_for(detail)?, 0)
+    }
+
+    /// Geodesic icosahedron whose edges are split into `frequency` segments,
+    /// giving `10 * frequency^2 + 2` vertices.
+    pub fn new_isocahedron_with_frequency(
+        radius: S,
+        frequency: u32,
+    ) -> Result<Polyhedron<S>, PolyhedronError> {
+        Polyhedron::new_goldberg_isocahedron(radius, frequency, 0)
+    }
+
+    /// Geodesic icosahedron for the Goldberg polyhedron GP(m, n): class I when
+    /// `n == 0`, class II when `m == n` and class III otherwise. It has
+    /// `10 * (m^2 + m * n + n^2) + 2` vertices.
+    pub fn new_goldberg_isocahedron(
+        radius: S,
+        m: u32,
+        n: u32,
+    ) -> Result<Polyhedron<S>, PolyhedronError> {
+        Polyhedron::new_projected_isocahedron(radius, m, n, Projection::Gnomonic)
+    }
+
+    /// GP(m, n) geodesic icosahedron whose lattice is moved onto the sphere
+    /// with `projection`.
+    pub fn new_projected_isocahedron(
+        radius: S,
+        m: u32,
+        n: u32,
+        projection: Projection,
+    ) -> Result<Polyhedron<S>, PolyhedronError> {
+        Polyhedron::new_geodesic(BaseSolid::Icosahedron, radius, m, n, projection)
+    }
 
+    /// Geodesic octahedron whose edges are split into `frequency` segments,
+    /// giving `4 * frequency^2 + 2` vertices.
+    pub fn new_octahedron_with_frequency(
+        radius: S,
+        frequency: u32,
+    ) -> Result<Polyhedron<S>, PolyhedronError> {
+        Polyhedron::new_geodesic(
+            BaseSolid::Octahedron,
+            radius,
+            frequency,
+            0,
+            Projection::Gnomonic,
+        )
+    }
+
+    /// Geodesic tetrahedron whose edges are split into `frequency` segments,
+    /// giving `2 * frequency^2 + 2` vertices.
+    pub fn new_tetrahedron_with_frequency(
+        radius: S,
+        frequency: u32,
+    ) -> Result<Polyhedron<S>, PolyhedronError> {
+        Polyhedron::new_geodesic(
+            BaseSolid::Tetrahedron,
+            radius,
+            frequency,
+            0,
+            Projection::Gnomonic,
+        )
+    }
+
+    /// GP(m, n) subdivision of any base solid, moved onto the sphere with
+    /// `projection`.
+    pub fn new_geodesic(
+        base: BaseSolid,
+        radius: S,
+        m: u32,
+        n: u32,
+        projection: Projection,
+    ) -> Result<Polyhedron<S>, PolyhedronError> {
+        let start = Instant::now();
+        let (base, lattice, opposite) = Polyhedron::geodesic_base(base, radius, m, n, projection)?;
+        let face_triangles = (0..base.cells.len())
+            .map(|face_index| base.face_triangles(&opposite, face_index, &lattice))
+            .collect();
+        let numbering = Numbering::new(&lattice);
         let mut subdivided = Polyhedron::new();
-        subdivided.subdivide(base_isocahedron, radius, detail);
-        subdivided.triangles_to_faces();
-        subdivided
+        subdivided.merge_triangles(&numbering, face_triangles);
+        let positions = (0..numbering.len())
+            .map(|k| base.lattice_position(numbering.key(k), &lattice))
+            .collect();
+        subdivided.finish_subdivision(lattice, numbering, positions, start);
+        Ok(subdivided)
     }
 
-    pub fn new_truncated_isocahedron(radius: f32, detail: u32) -> Polyhedron {
-        let isocahedron = Polyhedron::new_isocahedron(radius, detail);
-        let mut truncated_isocahedron = Polyhedron::new();
-        truncated_isocahedron.truncated(isocahedron);
-        truncated_isocahedron
+    pub fn new_truncated_isocahedron(
+        radius: S,
+        detail: u32,
+    ) -> Result<Polyhedron<S>, PolyhedronError> {
+        Polyhedron::new_truncated_goldberg_isocahedron(radius, frequency_for(detail)?, 0)
     }
 
-    fn subdivide(&mut self, other: Polyhedron, radius: f32, detail: u32) {
-        for triangle in other.cells {
-            let a = other.positions[triangle.a].0;
-            let b = other.positions[triangle.b].0;
-            let c = other.positions[triangle.c].0;
-            self.subdivide_triangle(a, b, c, radius, detail);
+    pub fn new_truncated_isocahedron_with_frequency(
+        radius: S,
+        frequency: u32,
+    ) -> Result<Polyhedron<S>, PolyhedronError> {
+        Polyhedron::new_truncated_goldberg_isocahedron(radius, frequency, 0)
+    }
+
+    pub fn new_truncated_goldberg_isocahedron(
+        radius: S,
+        m: u32,
+        n: u32,
+    ) -> Result<Polyhedron<S>, PolyhedronError> {
+        Polyhedron::new_truncated_projected_isocahedron(radius, m, n, Projection::Gnomonic)
+    }
+
+    pub fn new_truncated_projected_isocahedron(
+        radius: S,
+        m: u32,
+        n: u32,
+        projection: Projection,
+    ) -> Result<Polyhedron<S>, PolyhedronError> {
+        Polyhedron::new_projected_isocahedron(radius, m, n, projection)?.into_truncated()
+    }
+
+    pub fn new_truncated_octahedron_with_frequency(
+        radius: S,
+        frequency: u32,
+    ) -> Result<Polyhedron<S>, PolyhedronError> {
+        Polyhedron::new_octahedron_with_frequency(radius, frequency)?.into_truncated()
+    }
+
+    pub fn new_truncated_tetrahedron_with_frequency(
+        radius: S,
+        frequency: u32,
+    ) -> Result<Polyhedron<S>, PolyhedronError> {
+        Polyhedron::new_tetrahedron_with_frequency(radius, frequency)?.into_truncated()
+    }
+
+    pub fn new_truncated_geodesic(
+        base: BaseSolid,
+        radius: S,
+        m: u32,
+        n: u32,
+        projection: Projection,
+    ) -> Result<Polyhedron<S>, PolyhedronError> {
+        Polyhedron::new_geodesic(base, radius, m, n, projection)?.into_truncated()
+    }
+
+    /// Truncates a geodesic polyhedron into its hexagon and pentagon tiles,
+    /// one per vertex. Fails unless the triangles form a closed surface.
+    pub fn into_truncated(self) -> Result<Polyhedron<S>, PolyhedronError> {
+        let mut truncated = Polyhedron::new();
+        truncated.truncated(self)?;
+        Ok(truncated)
+    }
+
+    fn base_solid(solid: BaseSolid) -> Polyhedron<S> {
+        let (zero, one) = (S::zero(), S::one());
+        let (corners, triangles) = match solid {
+            BaseSolid::Icosahedron => {
+                let t: S = scalar((1.0 + 5.0_f64.sqrt()) / 2.0);
+                (
+                    vec![
+                        Vector3::new(-one, t, zero),
+                        Vector3::new(one, t, zero),
+                        Vector3::new(-one, -t, zero),
+                        Vector3::new(one, -t, zero),
+                        Vector3::new(zero, -one, t),
+                        Vector3::new(zero, one, t),
+                        Vector3::new(zero, -one, -t),
+                        Vector3::new(zero, one, -t),
+                        Vector3::new(t, zero, -one),
+                        Vector3::new(t, zero, one),
+                        Vector3::new(-t, zero, -one),
+                        Vector3::new(-t, zero, one),
+                    ],
+                    vec![
+                        [0, 11, 5],
+                        [0, 5, 1],
+                        [0, 1, 7],
+                        [0, 7, 10],
+                        [0, 10, 11],
+                        [1, 5, 9],
+                        [5, 11, 4],
+                        [11, 10, 2],
+                        [10, 7, 6],
+                        [7, 1, 8],
+                        [3, 9, 4],
+                        [3, 4, 2],
+                        [3, 2, 6],
+                        [3, 6, 8],
+                        [3, 8, 9],
+                        [4, 9, 5],
+                        [2, 4, 11],
+                        [6, 2, 10],
+                        [8, 6, 7],
+                        [9, 8, 1],
+                    ],
+                )
+            }
+            BaseSolid::Octahedron => (
+                vec![
+                    Vector3::new(zero, one, zero),
+                    Vector3::new(one, zero, zero),
+                    Vector3::new(zero, zero, one),
+                    Vector3::new(-one, zero, zero),
+                    Vector3::new(zero, zero, -one),
+                    Vector3::new(zero, -one, zero),
+                ],
+                vec![
+                    [0, 2, 1],
+                    [0, 1, 4],
+                    [0, 4, 3],
+                    [0, 3, 2],
+                    [5, 1, 2],
+                    [5, 4, 1],
+                    [5, 3, 4],
+                    [5, 2, 3],
+                ],
+            ),
+            BaseSolid::Tetrahedron => (
+                vec![
+                    Vector3::new(one, one, one),
+                    Vector3::new(one, -one, -one),
+                    Vector3::new(-one, one, -one),
+                    Vector3::new(-one, -one, one),
+                ],
+                vec![[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]],
+            ),
+        };
+
+        let mut base_solid = Polyhedron::new();
+        base_solid.cells = triangles
+            .into_iter()
+            .map(|[a, b, c]| Triangle::new(a, b, c))
+            .collect();
+        for corner in corners {
+            base_solid.push_vertex(corner);
         }
+        base_solid
     }
 
-    fn triangles_to_faces(&mut self) {
-        for (cell_index, _) in self.cells.iter().enumerate() {
-            self.faces.push(vec![cell_index]);
+    /// The base solid to subdivide into GP(m, n), with the lattice laid over
+    /// it and the neighbours of its triangles.
+    fn geodesic_base(
+        base: BaseSolid,
+        radius: S,
+        m: u32,
+        n: u32,
+        projection: Projection,
+    ) -> Result<(Polyhedron<S>, Lattice<S>, OppositeFaces), PolyhedronError> {
+        let lattice = Lattice {
+            base,
+            m: m as i64,
+            n: n as i64,
+            radius,
+            projection,
+        };
+        // Every base solid has at most 20 faces, so the truncation has at
+        // most 120t triangles with 360t corners between them, all counted in
+        // `u32`.
+        if m + n == 0 || lattice.t() > u32::MAX as i64 / 360 {
+            return Err(PolyhedronError::InvalidFrequency { m, n });
         }
+        let base = Polyhedron::base_solid(base);
+        base.check_surface()?;
+        let opposite = base.opposite_faces()?;
+        Ok((base, lattice, opposite))
     }
 
-    fn subdivide_triangle(
+    /// Adds the subdivided triangles, numbering their vertices by
+    /// `numbering` so that shared keys merge without a lookup table. The
+    /// positions are added afterwards by `finish_subdivision`, so they can
+    /// be computed in any order.
+    fn merge_triangles(&mut self, numbering: &Numbering, face_triangles: Vec<Vec<[VertexKey; 3]>>) {
+        for keys in face_triangles.into_iter().flatten() {
+            let [a, b, c] = keys.map(|key| numbering.index(key));
+            self.cells.push(Triangle::new(a, b, c));
+        }
+        self.stats.shared_corners += 3 * self.cells.len() - numbering.len();
+    }
+
+    fn finish_subdivision(
         &mut self,
-        a: Vector3<f32>,
-        b: Vector3<f32>,
-        c: Vector3<f32>,
-        radius: f32,
-        detail: u32,
+        lattice: Lattice<S>,
+        numbering: Numbering,
+        positions: Vec<Vector3<S>>,
+        start: Instant,
     ) {
-        let cols = 2usize.pow(detail);
-        let mut new_vertices: Vec<Vec<Vector3<f32>>> = vec![];
-
-        for i in 0..=cols {
-            new_vertices.push(vec![]);
-            let aj = a.clone().lerp(c, i as f32 / cols as f32);
-            let bj = b.clone().lerp(c, i as f32 / cols as f32);
-            let rows = cols - i;
-
-            for j in 0..=rows {
-                if j == 0 && i == cols {
-                    new_vertices[i].push(aj.normalize() * radius);
-                } else {
-                    new_vertices[i]
-                        .push(aj.clone().lerp(bj, j as f32 / rows as f32).normalize() * radius);
+        for position in positions {
+            self.push_vertex(position);
+        }
+        self.faces = Adjacency::identity(self.cells.len());
+        self.lattice = Some(lattice);
+        self.numbering = Some(numbering);
+        self.stats.vertices = self.positions.len();
+        self.stats.triangles = self.cells.len();
+        self.stats.duration = start.elapsed();
+    }
+
+    /// Checks that the triangles form a closed surface: every edge shared by
+    /// exactly two triangles running opposite ways, and every vertex used.
+    fn check_surface(&self) -> Result<(), PolyhedronError> {
+        let mut edges: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut used = vec![false; self.positions.len()];
+        for (face_index, triangle) in self.cells.iter().enumerate() {
+            let corners = triangle.corners();
+            for k in 0..3 {
+                let (a, b) = (corners[k], corners[(k + 1) % 3]);
+                if a >= self.positions.len() {
+                    return Err(PolyhedronError::VertexOutOfRange {
+                        face: face_index,
+                        vertex: a,
+                    });
+                }
+                used[a] = true;
+                *edges.entry((a, b)).or_default() += 1;
+            }
+        }
+        // Each edge once each way, so that neighbouring triangles wind the
+        // same way round.
+        for triangle in &self.cells {
+            let corners = triangle.corners();
+            for k in 0..3 {
+                let (a, b) = (corners[k], corners[(k + 1) % 3]);
+                if a == b || edges[&(a, b)] != 1 || edges.get(&(b, a)) != Some(&1) {
+                    return Err(PolyhedronError::NonManifoldEdge {
+                        a: a.min(b),
+                        b: a.max(b),
+                    });
                 }
             }
         }
+        match used.iter().position(|used| !used) {
+            Some(vertex) => Err(PolyhedronError::IsolatedVertex(vertex)),
+            None => Ok(()),
+        }
+    }
+
+    /// For every triangle, the neighbouring triangle across the edge opposite
+    /// each of its corners along with that neighbour's far vertex.
+    fn opposite_faces(&self) -> Result<OppositeFaces, PolyhedronError> {
+        let mut edges: HashMap<(usize, usize), Vec<(usize, usize)>> = HashMap::new();
+        for (face_index, triangle) in self.cells.iter().enumerate() {
+            let corners = triangle.corners();
+            for k in 0..3 {
+                let (b, c) = (corners[(k + 1) % 3], corners[(k + 2) % 3]);
+                edges
+                    .entry((b.min(c), b.max(c)))
+                    .or_default()
+                    .push((face_index, corners[k]));
+            }
+        }
 
-        for i in 0..cols {
-            for j in 0..2 * (cols - i) - 1 {
-                let k = j / 2;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(face_index, triangle)| {
+                let corners = triangle.corners();
+                let mut opposite = [(0, 0); 3];
+                for k in 0..3 {
+                    let (b, c) = (corners[(k + 1) % 3], corners[(k + 2) % 3]);
+                    opposite[k] = *edges[&(b.min(c), b.max(c))]
+                        .iter()
+                        .find(|(other_face, _)| *other_face != face_index)
+                        .ok_or(PolyhedronError::NonManifoldEdge {
+                            a: b.min(c),
+                            b: b.max(c),
+                        })?;
+                }
+                Ok(opposite)
+            })
+            .collect()
+    }
 
-                let mut triangle = Triangle { a: 0, b: 0, c: 0 };
-                if j % 2 == 0 {
-                    triangle.a = self.add_position(new_vertices[i][k + 1]);
-                    triangle.b = self.add_position(new_vertices[i + 1][k]);
-                    triangle.c = self.add_position(new_vertices[i][k]);
-                } else {
-                    triangle.a = self.add_position(new_vertices[i][k + 1]);
-                    triangle.b = self.add_position(new_vertices[i + 1][k + 1]);
-                    triangle.c = self.add_position(new_vertices[i + 1][k]);
+    /// Lays the triangular lattice spanned by (m, n) over one base triangle and
+    /// lists the vertex keys of every unit triangle whose centroid falls
+    /// inside it. Lattice point (i, j) sits at `i * e1 + j * e2`, with the
+    /// base corners at (0, 0), (m, n) and (-n, m + n).
+    fn face_triangles(
+        &self,
+        opposite: &[[(usize, usize); 3]],
+        face_index: usize,
+        lattice: &Lattice<S>,
+    ) -> Vec<[VertexKey; 3]> {
+        let (m, n) = (lattice.m, lattice.n);
+        let weights = |i: i64, j: i64, scale: i64| lattice.weights(i, j, scale);
+        let mut triangles = vec![];
+
+        for j in -1..=m + n + 1 {
+            for i in -n - 1..=m + 1 {
+                let up = [(i, j), (i + 1, j), (i, j + 1)];
+                let down = [(i + 1, j), (i + 1, j + 1), (i, j + 1)];
+                for (lattice_triangle, offset) in [(up, 1), (down, 2)] {
+                    let centroid = weights(3 * i + offset, 3 * j + offset, 3);
+                    if centroid.iter().any(|w| *w < 0) {
+                        continue;
+                    }
+                    // A centroid on a base edge belongs to the lower numbered face.
+                    if let Some(k) = centroid.iter().position(|w| *w == 0) {
+                        if opposite[face_index][k].0 < face_index {
+                            continue;
+                        }
+                    }
+
+                    triangles.push(lattice_triangle.map(|(li, lj)| {
+                        let (point_face, point_weights) =
+                            self.lattice_point(face_index, opposite, weights(li, lj, 1));
+                        self.lattice_key(point_face, point_weights, lattice.edge_step())
+                    }));
                 }
+            }
+        }
+        triangles
+    }
+
+    fn corners(&self, face_index: usize) -> [usize; 3] {
+        let triangle = &self.cells[face_index];
+        triangle.corners()
+    }
+
+    /// Moves lattice weights that spill over an edge of `face_index` into the
+    /// neighbouring triangle. Unfolded into the plane, that neighbour's far
+    /// vertex sits at `b + c - a`, which gives its weights directly.
+    fn lattice_point(
+        &self,
+        face_index: usize,
+        opposite: &[[(usize, usize); 3]],
+        weights: [i64; 3],
+    ) -> (usize, [i64; 3]) {
+        let Some(k) = weights.iter().position(|w| *w < 0) else {
+            return (face_index, weights);
+        };
+        let corners = self.corners(face_index);
+        let (neighbour, far_vertex) = opposite[face_index][k];
+        let unfolded = [
+            (far_vertex, -weights[k]),
+            (corners[(k + 1) % 3], weights[(k + 1) % 3] + weights[k]),
+            (corners[(k + 2) % 3], weights[(k + 2) % 3] + weights[k]),
+        ];
+        let neighbour_weights = self.corners(neighbour).map(|corner| {
+            unfolded
+                .iter()
+                .find(|(vertex, _)| *vertex == corner)
+                .unwrap()
+                .1
+        });
+        debug_assert!(neighbour_weights.iter().all(|w| *w >= 0));
+        (neighbour, neighbour_weights)
+    }
 
-                self.cells.push(triangle);
+    fn lattice_key(&self, face_index: usize, weights: [i64; 3], edge_step: i64) -> VertexKey {
+        let corners = self.corners(face_index);
+        let zeros: Vec<usize> = (0..3).filter(|k| weights[*k] == 0).collect();
+        match zeros[..] {
+            [a, b] => VertexKey::Corner(corners[3 - a - b]),
+            [k] => {
+                let (b, c) = ((k + 1) % 3, (k + 2) % 3);
+                let (low, high) = if corners[b] < corners[c] {
+                    (b, c)
+                } else {
+                    (c, b)
+                };
+                VertexKey::Edge(corners[low], corners[high], weights[high] / edge_step)
             }
+            _ => VertexKey::Face(face_index, weights[1], weights[2]),
         }
     }
 
-    fn add_position(&mut self, vertex: Vector3<f32>) -> usize {
-        let vertex_key = (
-            (vertex.x * VERT_CACHE_PRECISION).round() as i32,
-            (vertex.y * VERT_CACHE_PRECISION).round() as i32,
-            (vertex.z * VERT_CACHE_PRECISION).round() as i32,
-        );
-        if let Some(added_vert_index) = self.added_vert_cache.get(&vertex_key) {
-            return *added_vert_index;
+    /// Position of a subdivision vertex, computed from its key alone so that
+    /// every triangle sharing it agrees on it exactly. Corners and edge points
+    /// are projected within the lowest numbered base triangle touching them.
+    fn lattice_position(&self, key: VertexKey, lattice: &Lattice<S>) -> Vector3<S> {
+        let t = lattice.t();
+        let point = match key {
+            VertexKey::Corner(vertex) => vec![(vertex, t)],
+            VertexKey::Edge(low, high, step) => {
+                let high_weight = step * lattice.edge_step();
+                vec![(low, t - high_weight), (high, high_weight)]
+            }
+            VertexKey::Face(face_index, u, v) => {
+                let corners = self.corners(face_index);
+                vec![(corners[0], t - u - v), (corners[1], u), (corners[2], v)]
+            }
+        };
+
+        let face_index = (0..self.cells.len())
+            .find(|face_index| {
+                let corners = self.corners(*face_index);
+                point.iter().all(|(vertex, _)| corners.contains(vertex))
+            })
+            .unwrap();
+        let corners = self.corners(face_index);
+        let weights = corners.map(|corner| {
+            point
+                .iter()
+                .find(|(vertex, _)| *vertex == corner)
+                .map_or(0, |(_, weight)| *weight)
+        });
+        let positions = corners.map(|corner| self.positions[corner].0);
+        lattice.projection.project(positions, weights, t) * lattice.radius
+    }
+
+    /// Structured address of a subdivision vertex, placing edge points in the
+    /// lowest numbered base triangle touching them like `lattice_position`.
+    fn lattice_address<T>(&self, key: VertexKey, lattice: &Lattice<T>) -> TileAddress {
+        let (face, u, v) = match key {
+            VertexKey::Corner(vertex) => return TileAddress::Vertex(vertex),
+            VertexKey::Edge(low, high, step) => {
+                let face = (0..self.cells.len())
+                    .find(|face_index| {
+                        let corners = self.corners(*face_index);
+                        corners.contains(&low) && corners.contains(&high)
+                    })
+                    .unwrap();
+                let high_weight = step * lattice.edge_step();
+                let weight = |k: usize| match self.corners(face)[k] {
+                    vertex if vertex == high => high_weight,
+                    vertex if vertex == low => lattice.t() - high_weight,
+                    _ => 0,
+                };
+                (face, weight(1), weight(2))
+            }
+            VertexKey::Face(face, u, v) => (face, u, v),
+        };
+        let (i, j) = lattice.coordinates(u, v);
+        TileAddress::Face { face, i, j }
+    }
+
+    fn push_vertex(&mut self, vertex: Vector3<S>) -> usize {
+        self.positions.push(ArraySerializedVector(vertex));
+        self.normals.push(ArraySerializedVector(Vector3::zero()));
+        self.colors
+            .push(ArraySerializedVector(Vector3::new(1.0, 1.0, 1.0)));
+        self.positions.len() - 1
+    }
+
+    /// The vertex already numbered in `slot`, or a new one at `vertex`.
+    fn shared_vertex_index(&mut self, slot: &mut usize, vertex: Vector3<S>) -> usize {
+        if *slot == UNNUMBERED {
+            *slot = self.push_vertex(vertex);
         } else {
-            self.positions.push(ArraySerializedVector(vertex));
-            self.normals
-                .push(ArraySerializedVector(Vector3::new(0.0, 0.0, 0.0)));
-            self.colors
-                .push(ArraySerializedVector(Vector3::new(1.0, 1.0, 1.0)));
-            let added_index = self.positions.len() - 1;
-            self.added_vert_cache.insert(vertex_key, added_index);
-            return added_index;
+            self.stats.shared_corners += 1;
         }
+        *slot
     }
 
-    fn truncated(&mut self, other: Polyhedron) {
+    fn truncated(&mut self, other: Polyhedron<S>) -> Result<(), PolyhedronError> {
+        let start = Instant::now();
+        other.check_surface()?;
         let vert_to_faces = other.vert_to_faces();
-        let original_vert_count = other.positions.len();
         let triangle_centroids = other.triangle_centroids();
-        let mut mid_centroid_cache: HashMap<(usize, usize, usize), Vector3<f32>> = HashMap::new();
-        let mut hex_count = 0;
-        let mut pent_count = 0;
-        for i in 0..original_vert_count {
-            let faces = &vert_to_faces[&i];
-            if faces.len() == 6 {
-                hex_count += 1;
-            } else {
-                pent_count += 1;
-            }
+        let mut shared = other.shared_vertices();
+        for i in 0..other.positions.len() {
+            let plan = other.plan_tile(i, &vert_to_faces, &triangle_centroids)?;
+            self.add_tile(plan, &triangle_centroids, &mut shared);
+        }
+        self.finish_truncation(other, start);
+        Ok(())
+    }
 
-            let center_point = find_center_of_triangles(faces, &triangle_centroids);
-
-            let mut new_face = Vec::new();
-
-            for face_index in faces.iter().rev() {
-                let triangle = &other.cells[*face_index];
-                let other_verts: Vec<usize> = vec![triangle.a, triangle.b, triangle.c]
-                    .drain(..)
-                    .filter(|vert| *vert != i)
-                    .collect();
-                let sorted_triangle = Triangle::new(i, other_verts[0], other_verts[1]);
-
-                let centroid = triangle_centroids[face_index];
-                let mid_b_centroid = other.calculate_mid_centroid(
-                    sorted_triangle.a,
-                    sorted_triangle.b,
-                    faces,
-                    *face_index,
-                    centroid,
-                    &triangle_centroids,
-                    &mut mid_centroid_cache,
-                );
-                let mid_c_centroid = other.calculate_mid_centroid(
-                    sorted_triangle.a,
-                    sorted_triangle.c,
-                    faces,
-                    *face_index,
-                    centroid,
-                    &triangle_centroids,
-                    &mut mid_centroid_cache,
-                );
-
-                let center_point_index = self.add_position(center_point);
-                let centroid_index = self.add_position(centroid);
-                let mid_b_centroid_index = self.add_position(mid_b_centroid);
-                let mid_c_centroid_index = self.add_position(mid_c_centroid);
-
-                self.cells.push(Triangle::new(
-                    center_point_index,
-                    mid_c_centroid_index,
-                    centroid_index,
-                ));
-                new_face.push(self.cells.len() - 1);
-                self.cells.push(Triangle::new(
-                    center_point_index,
-                    centroid_index,
-                    mid_b_centroid_index,
-                ));
-                new_face.push(self.cells.len() - 1);
+    fn shared_vertices(&self) -> SharedVertices {
+        SharedVertices {
+            centroids: vec![UNNUMBERED; self.cells.len()],
+            mid_centroids: vec![[UNNUMBERED; 3]; self.cells.len()],
+        }
+    }
+
+    /// Works out the tile around vertex `i` from the untruncated polyhedron
+    /// alone, leaving `add_tile` to number its vertices.
+    fn plan_tile(
+        &self,
+        i: usize,
+        vert_to_faces: &Adjacency,
+        triangle_centroids: &[Vector3<S>],
+    ) -> Result<TilePlan<S>, PolyhedronError> {
+        let faces = &vert_to_faces[i];
+        if faces.is_empty() {
+            return Err(PolyhedronError::IsolatedVertex(i));
+        }
+        let center = find_center_of_triangles(faces, triangle_centroids);
+        let ring = self.ordered_ring(i, faces, triangle_centroids)?;
+
+        // The vertex shared by consecutive triangles of the ring is the
+        // neighbouring tile across the edge between their centroids.
+        let neighbours: Vec<usize> = (0..ring.len())
+            .map(|k| self.shared_vertex(i, ring[k], ring[(k + 1) % ring.len()]))
+            .collect();
+
+        let mut corners = Vec::with_capacity(ring.len());
+        for (k, face_index) in ring.iter().enumerate() {
+            let previous_neighbour = neighbours[(k + ring.len() - 1) % ring.len()];
+            let mid_prev = self.calculate_mid_centroid(
+                i,
+                previous_neighbour,
+                faces,
+                *face_index,
+                triangle_centroids,
+            )?;
+            let mid_next = self.calculate_mid_centroid(
+                i,
+                neighbours[k],
+                faces,
+                *face_index,
+                triangle_centroids,
+            )?;
+            corners.push((*face_index, mid_prev, mid_next));
+        }
+        Ok(TilePlan {
+            center,
+            neighbours,
+            corners,
+        })
+    }
+
+    /// Adds a planned tile as a fan of two triangles per boundary corner.
+    fn add_tile(
+        &mut self,
+        plan: TilePlan<S>,
+        triangle_centroids: &[Vector3<S>],
+        shared: &mut SharedVertices,
+    ) {
+        match plan.corners.len() {
+            6 => self.stats.hexagons += 1,
+            5 => self.stats.pentagons += 1,
+            _ => self.stats.other_tiles += 1,
+        }
+
+        let center_point_index = self.push_vertex(plan.center);
+        let mut new_face = Vec::with_capacity(2 * plan.corners.len());
+        let mut boundary = Vec::with_capacity(plan.corners.len());
+        for (face_index, (prev_slot, mid_prev_centroid), (next_slot, mid_next_centroid)) in
+            plan.corners
+        {
+            let centroid_index = self.shared_vertex_index(
+                &mut shared.centroids[face_index],
+                triangle_centroids[face_index],
+            );
+            let mid_prev_centroid_index = self.shared_vertex_index(
+                &mut shared.mid_centroids[prev_slot.0][prev_slot.1],
+                mid_prev_centroid,
+            );
+            let mid_next_centroid_index = self.shared_vertex_index(
+                &mut shared.mid_centroids[next_slot.0][next_slot.1],
+                mid_next_centroid,
+            );
+            boundary.push(centroid_index as u32);
+
+            self.cells.push(Triangle::new(
+                center_point_index,
+                mid_prev_centroid_index,
+                centroid_index,
+            ));
+            new_face.push(self.cells.len() as u32 - 1);
+            self.cells.push(Triangle::new(
+                center_point_index,
+                centroid_index,
+                mid_next_centroid_index,
+            ));
+            new_face.push(self.cells.len() as u32 - 1);
+        }
+        self.faces.push_row(new_face);
+        self.tile_centers.push(center_point_index as u32);
+        self.tile_boundaries.push_row(boundary);
+        self.tile_neighbours.push_row(
+            plan.neighbours
+                .into_iter()
+                .map(|neighbour| neighbour as u32),
+        );
+    }
+
+    fn finish_truncation(&mut self, other: Polyhedron<S>, start: Instant) {
+        self.lattice = other.lattice;
+        self.numbering = other.numbering;
+        self.stats.vertices = self.positions.len();
+        self.stats.triangles = self.cells.len();
+        self.stats.shared_corners += other.stats.shared_corners;
+        self.stats.duration = other.stats.duration + start.elapsed();
+    }
+
+    /// Orders the triangles around `vertex_index` into a loop that winds
+    /// counter-clockwise when seen from outside the sphere.
+    fn ordered_ring(
+        &self,
+        vertex_index: usize,
+        faces: &[u32],
+        triangle_centroids: &[Vector3<S>],
+    ) -> Result<Vec<usize>, PolyhedronError> {
+        let mut ring = vec![faces[0] as usize];
+        let mut previous_vertex = self.other_vertices(vertex_index, ring[0]).0;
+        while ring.len() < faces.len() {
+            let current = ring[ring.len() - 1];
+            let (b, c) = self.other_vertices(vertex_index, current);
+            let spoke = if b == previous_vertex { c } else { b };
+            let next = self
+                .find_adjacent_face(vertex_index, spoke, faces, current)
+                .filter(|next| !ring.contains(next))
+                .ok_or(PolyhedronError::MissingAdjacentFace {
+                    vertex: vertex_index,
+                    face: current,
+                })?;
+            ring.push(next);
+            previous_vertex = spoke;
+        }
+
+        let center = self.positions[vertex_index].0;
+        let first = triangle_centroids[ring[0]];
+        let second = triangle_centroids[ring[1]];
+        if (first - center).cross(second - center).dot(center) < S::zero() {
+            ring.reverse();
+        }
+        Ok(ring)
+    }
+
+    fn other_vertices(&self, vertex_index: usize, face_index: usize) -> (usize, usize) {
+        let [a, b, c] = self.corners(face_index);
+        if a == vertex_index {
+            (b, c)
+        } else if b == vertex_index {
+            (c, a)
+        } else {
+            (a, b)
+        }
+    }
+
+    fn shared_vertex(&self, vertex_index: usize, face_a: usize, face_b: usize) -> usize {
+        let (b, c) = self.other_vertices(vertex_index, face_a);
+        let (d, e) = self.other_vertices(vertex_index, face_b);
+        if b == d || b == e {
+            b
+        } else {
+            c
+        }
+    }
+
+    /// Counts and timings from generating this polyhedron. All zero for one
+    /// assembled by hand.
+    pub fn generation_stats(&self) -> &GenerationStats {
+        &self.stats
+    }
+
+    /// Per-tile topology of a truncated polyhedron, in tile order. Empty for
+    /// polyhedra that have not been truncated.
+    pub fn tiles(&self) -> impl ExactSizeIterator<Item = TileTopology<'_>> + '_ {
+        (0..self.tile_count()).map(|tile_index| self.tile(tile_index))
+    }
+
+    /// Topology of one tile of a truncated polyhedron.
+    pub fn tile(&self, tile_index: usize) -> TileTopology<'_> {
+        TileTopology {
+            center: self.tile_centers[tile_index],
+            boundary: &self.tile_boundaries[tile_index],
+            neighbours: &self.tile_neighbours[tile_index],
+        }
+    }
+
+    pub fn tile_count(&self) -> usize {
+        self.tile_centers.len()
+    }
+
+    /// Radius of the sphere the polyhedron was generated on, or for one
+    /// assembled by hand, the distance of its farthest vertex.
+    pub fn radius(&self) -> S {
+        match self.lattice {
+            Some(lattice) => lattice.radius,
+            None => self.positions.iter().fold(S::zero(), |radius, position| {
+                radius.max(position.0.magnitude())
+            }),
+        }
+    }
+
+    /// The neighbours of every tile, row per tile, for graph algorithms over
+    /// the whole sphere.
+    pub fn tile_neighbours(&self) -> &Adjacency {
+        &self.tile_neighbours
+    }
+
+    pub fn tile_center(&self, tile_index: usize) -> Vector3<S> {
+        self.positions[self.tile_centers[tile_index] as usize].0
+    }
+
+    /// Structured address of a tile, or of a vertex before truncation. `None`
+    /// for polyhedra not built from the lattice of a base solid.
+    pub fn tile_address(&self, tile_index: usize) -> Option<TileAddress> {
+        self.numbering.as_ref()?.address(tile_index)
+    }
+
+    /// Index of the tile, or vertex before truncation, at `address`.
+    pub fn tile_at(&self, address: TileAddress) -> Option<usize> {
+        self.numbering.as_ref()?.address_index(address)
+    }
+
+    /// Addresses of the tiles next to `address`, worked out from the lattice
+    /// alone and in no particular order. `None` if the polyhedron has no
+    /// tile at `address`.
+    pub fn address_neighbours(&self, address: TileAddress) -> Option<Vec<TileAddress>> {
+        self.tile_at(address)?;
+        let lattice = self.lattice?;
+        let (base, _) = base_lattice(lattice.base);
+        let (m, n) = (lattice.m, lattice.n);
+
+        // A pentagon's neighbours are spread over the five triangles around
+        // it, so look around its corner in each of them.
+        let origins: Vec<(usize, (i64, i64))> = match address {
+            TileAddress::Vertex(vertex) => (0..base.cells.len())
+                .filter_map(|face| {
+                    let k = base
+                        .corners(face)
+                        .iter()
+                        .position(|corner| *corner == vertex)?;
+                    Some((face, [(0, 0), (m, n), (-n, m + n)][k]))
+                })
+                .collect(),
+            TileAddress::Face { face, i, j } => vec![(face, (i, j))],
+        };
+
+        let mut neighbours = vec![];
+        for (face, (i, j)) in origins {
+            for (di, dj) in [(1, 0), (0, 1), (-1, 1), (-1, 0), (0, -1), (1, -1)] {
+                let weights = lattice.weights(i + di, j + dj, 1);
+                if matches!(address, TileAddress::Vertex(_)) && weights.iter().any(|w| *w < 0) {
+                    continue;
+                }
+                let neighbour = lattice_point_address(&lattice, face, i + di, j + dj);
+                if !neighbours.contains(&neighbour) {
+                    neighbours.push(neighbour);
+                }
             }
-            self.faces.push(new_face);
         }
-        println!("hexagons: {}", hex_count);
-        println!("pentagons: {}", pent_count);
+        Some(neighbours)
+    }
+
+    /// Area of every tile on the unit sphere, measured through the tile's
+    /// center and corners.
+    pub fn tile_areas(&self) -> Vec<S> {
+        (0..self.tile_count())
+            .map(|tile_index| {
+                let center = self.tile_center(tile_index).normalize();
+                let boundary = &self.tile_boundaries[tile_index];
+                (0..boundary.len())
+                    .map(|k| {
+                        let a = self.positions[boundary[k] as usize].0.normalize();
+                        let b = self.positions[boundary[(k + 1) % boundary.len()] as usize]
+                            .0
+                            .normalize();
+                        spherical_triangle_area(center, a, b)
+                    })
+                    .fold(S::zero(), |sum, area| sum + area)
+            })
+            .collect()
+    }
+
+    /// Largest hexagon area over the smallest; 1 would mean all hexagons are
+    /// equal. Pentagons are left out as they are smaller by construction,
+    /// unless there are no hexagons at all.
+    pub fn tile_area_ratio(&self) -> S {
+        let areas = self.tile_areas();
+        let has_hexagons = self.tiles().any(|tile| tile.is_hex());
+        let (largest, smallest) = areas
+            .iter()
+            .zip(self.tiles())
+            .filter(|(_, tile)| tile.is_hex() || !has_hexagons)
+            .fold((S::zero(), S::infinity()), |(max, min), (area, _)| {
+                (max.max(*area), min.min(*area))
+            });
+        largest / smallest
     }
 
-    pub fn unique_vertices(&mut self, other: Polyhedron) {
+    pub fn unique_vertices(&mut self, other: Polyhedron<S>) {
         for triangle in other.cells {
-            let vertex_a = other.positions[triangle.a].0;
-            let vertex_b = other.positions[triangle.b].0;
-            let vertex_c = other.positions[triangle.c].0;
-            let normal_a = other.normals[triangle.a].0;
-            let normal_b = other.normals[triangle.b].0;
-            let normal_c = other.normals[triangle.c].0;
+            let [a, b, c] = triangle.corners();
+            let vertex_a = other.positions[a].0;
+            let vertex_b = other.positions[b].0;
+            let vertex_c = other.positions[c].0;
+            let normal_a = other.normals[a].0;
+            let normal_b = other.normals[b].0;
+            let normal_c = other.normals[c].0;
 
             self.positions.push(ArraySerializedVector(vertex_a));
             self.positions.push(ArraySerializedVector(vertex_b));
@@ -323,102 +1216,77 @@ impl Polyhedron {
         self.faces = other.faces;
     }
 
-    fn vert_to_faces(&self) -> HashMap<usize, Vec<usize>> {
-        let mut vert_to_faces: HashMap<usize, Vec<usize>> = HashMap::new();
-        for i in 0..self.cells.len() {
-            let triangle = &self.cells[i];
-
-            if let Some(faces) = vert_to_faces.get_mut(&triangle.a) {
-                faces.push(i);
-            } else {
-                vert_to_faces.insert(triangle.a, vec![i]);
-            }
-
-            if let Some(faces) = vert_to_faces.get_mut(&triangle.b) {
-                faces.push(i);
-            } else {
-                vert_to_faces.insert(triangle.b, vec![i]);
-            }
-
-            if let Some(faces) = vert_to_faces.get_mut(&triangle.c) {
-                faces.push(i);
-            } else {
-                vert_to_faces.insert(triangle.c, vec![i]);
-            }
-        }
-        vert_to_faces
+    /// The triangles around every vertex, in triangle order.
+    fn vert_to_faces(&self) -> Adjacency {
+        let pairs = self
+            .cells
+            .iter()
+            .enumerate()
+            .flat_map(|(i, triangle)| triangle.corners().map(|corner| (corner, i)));
+        Adjacency::from_pairs(self.positions.len(), pairs)
     }
 
-    fn triangle_centroids(&self) -> HashMap<usize, Vector3<f32>> {
-        let mut triangle_centroids: HashMap<usize, Vector3<f32>> = HashMap::new();
-        for i in 0..self.cells.len() {
-            let a = self.positions[self.cells[i].a].0;
-            let b = self.positions[self.cells[i].b].0;
-            let c = self.positions[self.cells[i].c].0;
-            triangle_centroids.insert(i, calculate_centroid(a, b, c));
-        }
-        triangle_centroids
+    fn triangle_centroids(&self) -> Vec<Vector3<S>> {
+        self.cells
+            .iter()
+            .map(|triangle| {
+                let [a, b, c] = triangle.corners().map(|corner| self.positions[corner].0);
+                calculate_centroid(a, b, c)
+            })
+            .collect()
     }
 
     fn calculate_mid_centroid(
         &self,
         spoke_vertex_index: usize,
         vertex_index: usize,
-        faces: &Vec<usize>,
+        faces: &[u32],
         current_face_index: usize,
-        centroid: Vector3<f32>,
-        triangle_centroids: &HashMap<usize, Vector3<f32>>,
-        mid_centroid_cache: &mut HashMap<(usize, usize, usize), Vector3<f32>>,
-    ) -> Vector3<f32> {
+        triangle_centroids: &[Vector3<S>],
+    ) -> Result<EdgePoint<S>, PolyhedronError> {
         let adj_face_index = self
             .find_adjacent_face(spoke_vertex_index, vertex_index, faces, current_face_index)
+            .ok_or(PolyhedronError::MissingAdjacentFace {
+                vertex: spoke_vertex_index,
+                face: current_face_index,
+            })?;
+        let low = current_face_index.min(adj_face_index);
+        let high = current_face_index.max(adj_face_index);
+        let mid_centroid = triangle_centroids[low].lerp(triangle_centroids[high], scalar(0.5));
+        let edge = self
+            .corners(low)
+            .iter()
+            .position(|corner| *corner != spoke_vertex_index && *corner != vertex_index)
             .unwrap();
-        let adj_centroid = triangle_centroids[&adj_face_index];
-        if let Some(mid_centroid) =
-            mid_centroid_cache.get(&(spoke_vertex_index, vertex_index, adj_face_index))
-        {
-            return *mid_centroid;
-        } else {
-            let mid_centroid = centroid.clone().lerp(adj_centroid, 0.5);
-            mid_centroid_cache.insert(
-                (spoke_vertex_index, vertex_index, adj_face_index),
-                mid_centroid,
-            );
-            return mid_centroid;
-        }
+        Ok(((low, edge), mid_centroid))
     }
 
     fn find_adjacent_face(
         &self,
         spoke_vertex_index: usize,
         vertex_index: usize,
-        faces: &Vec<usize>,
+        faces: &[u32],
         current_face_index: usize,
     ) -> Option<usize> {
-        for face_index in faces {
-            if *face_index == current_face_index {
+        for face_index in faces.iter().map(|face_index| *face_index as usize) {
+            if face_index == current_face_index {
                 continue;
             }
-            let triangle = &self.cells[*face_index];
-            if (triangle.a == spoke_vertex_index
-                || triangle.b == spoke_vertex_index
-                || triangle.c == spoke_vertex_index)
-                && (triangle.a == vertex_index
-                    || triangle.b == vertex_index
-                    || triangle.c == vertex_index)
-            {
-                return Some(*face_index);
+            let corners = self.corners(face_index);
+            if corners.contains(&spoke_vertex_index) && corners.contains(&vertex_index) {
+                return Some(face_index);
             }
         }
         None
     }
 
     pub fn compute_triangle_normals(&mut self) {
-        let origin = Vector3::new(0.0, 0.0, 0.0);
+        let origin = Vector3::zero();
         for i in 0..self.cells.len() {
-            let vertex_a = &self.positions[self.cells[i].a].0;
-            let vertex_b = &self.positions[self.cells[i].b].0;
-            let vertex_c = &self.positions[self.cells[i].c].0;
+            let [a, b, c] = self.corners(i);
+            let vertex_a = &self.positions[a].0;
+            let vertex_b = &self.positions[b].0;
+            let vertex_c = &self.positions[c].0;
 
             let e1 = vertex_a - vertex_b;
             let e2 = vertex_c - vertex_b;
@@ -426,17 +1294,17 @@ impl Polyhedron {
 
             // detect and correct inverted normal
             let dist = vertex_b - origin;
-            if no.dot(dist) < 0.0 {
-                no *= -1.0;
+            if no.dot(dist) < S::zero() {
+                no = -no;
             }
 
-            let normal_a = self.normals[self.cells[i].a].0 + no;
-            let normal_b = self.normals[self.cells[i].b].0 + no;
-            let normal_c = self.normals[self.cells[i].c].0 + no;
+            let normal_a = self.normals[a].0 + no;
+            let normal_b = self.normals[b].0 + no;
+            let normal_c = self.normals[c].0 + no;
 
-            self.normals[self.cells[i].a] = ArraySerializedVector(normal_a);
-            self.normals[self.cells[i].b] = ArraySerializedVector(normal_b);
-            self.normals[self.cells[i].c] = ArraySerializedVector(normal_c);
+            self.normals[a] = ArraySerializedVector(normal_a);
+            self.normals[b] = ArraySerializedVector(normal_b);
+            self.normals[c] = ArraySerializedVector(normal_c);
         }
 
         for normal in self.normals.iter_mut() {
@@ -445,13 +1313,13 @@ impl Polyhedron {
     }
 
     pub fn compute_face_normals(&mut self) {
-        let origin = Vector3::new(0.0, 0.0, 0.0);
+        let origin = Vector3::zero();
         for i in 0..self.faces.len() {
-            let first_cell = &self.cells[self.faces[i][0]];
+            let [a, b, c] = self.corners(self.faces[i][0] as usize);
 
-            let vertex_a = &self.positions[first_cell.a].0;
-            let vertex_b = &self.positions[first_cell.b].0;
-            let vertex_c = &self.positions[first_cell.c].0;
+            let vertex_a = &self.positions[a].0;
+            let vertex_b = &self.positions[b].0;
+            let vertex_c = &self.positions[c].0;
 
             let e1 = vertex_a - vertex_b;
             let e2 = vertex_c - vertex_b;
@@ -459,20 +1327,20 @@ impl Polyhedron {
 
             // detect and correct inverted normal
             let dist = vertex_b - origin;
-            if normal.dot(dist) < 0.0 {
-                normal *= -1.0;
+            if normal.dot(dist) < S::zero() {
+                normal = -normal;
             }
 
             for c in 0..self.faces[i].len() {
-                let face_cell = &self.cells[self.faces[i][c]];
+                let [a, b, c] = self.corners(self.faces[i][c] as usize);
 
-                let normal_a = self.normals[face_cell.a].0 + normal;
-                let normal_b = self.normals[face_cell.b].0 + normal;
-                let normal_c = self.normals[face_cell.c].0 + normal;
+                let normal_a = self.normals[a].0 + normal;
+                let normal_b = self.normals[b].0 + normal;
+                let normal_c = self.normals[c].0 + normal;
 
-                self.normals[face_cell.a] = ArraySerializedVector(normal_a);
-                self.normals[face_cell.b] = ArraySerializedVector(normal_b);
-                self.normals[face_cell.c] = ArraySerializedVector(normal_c);
+                self.normals[a] = ArraySerializedVector(normal_a);
+                self.normals[b] = ArraySerializedVector(normal_b);
+                self.normals[c] = ArraySerializedVector(normal_c);
             }
         }
 
@@ -481,46 +1349,307 @@ impl Polyhedron {
         }
     }
 
-    pub fn assign_random_face_colors(&mut self) {
-        let mut rng = rand::thread_rng();
+    /// Gives every face a random colour drawn from `rng`.
+    pub fn assign_random_face_colors<R: Rng + ?Sized>(&mut self, rng: &mut R) {
         for i in 0..self.faces.len() {
             let face_color = Vector3::new(rng.gen(), rng.gen(), rng.gen());
 
             for c in 0..self.faces[i].len() {
-                let face_cell = &self.cells[self.faces[i][c]];
-
-                self.colors[face_cell.a] = ArraySerializedVector(face_color);
-                self.colors[face_cell.b] = ArraySerializedVector(face_color);
-                self.colors[face_cell.c] = ArraySerializedVector(face_color);
+                for corner in self.corners(self.faces[i][c] as usize) {
+                    self.colors[corner] = ArraySerializedVector(face_color);
+                }
             }
         }
     }
 
+    /// Same as `assign_random_face_colors`, but the same seed always gives
+    /// the same colours, on any platform and release: ChaCha8 is a fixed
+    /// algorithm, unlike `StdRng`.
+    pub fn assign_seeded_face_colors(&mut self, seed: u64) {
+        self.assign_random_face_colors(&mut ChaCha8Rng::seed_from_u64(seed));
+    }
+
     pub fn export_cells(&self) -> Vec<u32> {
         let mut cell_vec: Vec<u32> = vec![];
         for cell in &self.cells {
-            cell_vec.push(cell.a as u32);
-            cell_vec.push(cell.b as u32);
-            cell_vec.push(cell.c as u32);
+            cell_vec.push(cell.a);
+            cell_vec.push(cell.b);
+            cell_vec.push(cell.c);
         }
         cell_vec
     }
+
+    /// Converts positions and normals to another scalar type, e.g. to hand an
+    /// `f64` polyhedron to the renderer as `f32`.
+    pub fn cast<T: BaseFloat>(&self) -> Polyhedron<T> {
+        let cast_vectors = |vectors: &Vec<ArraySerializedVector<S>>| {
+            vectors
+                .iter()
+                .map(|vector| ArraySerializedVector(vector.0.cast().unwrap()))
+                .collect()
+        };
+        Polyhedron {
+            positions: cast_vectors(&self.positions),
+            cells: self.cells.clone(),
+            normals: cast_vectors(&self.normals),
+            colors: self
+                .colors
+                .iter()
+                .map(|color| ArraySerializedVector(color.0))
+                .collect(),
+            faces: self.faces.clone(),
+            tile_centers: self.tile_centers.clone(),
+            tile_boundaries: self.tile_boundaries.clone(),
+            tile_neighbours: self.tile_neighbours.clone(),
+            lattice: self.lattice.map(|lattice| Lattice {
+                base: lattice.base,
+                m: lattice.m,
+                n: lattice.n,
+                radius: T::from(lattice.radius).unwrap(),
+                projection: lattice.projection,
+            }),
+            numbering: self.numbering.clone(),
+            stats: self.stats,
+        }
+    }
 }
 
-fn calculate_centroid(pa: Vector3<f32>, pb: Vector3<f32>, pc: Vector3<f32>) -> Vector3<f32> {
-    let vab_half = (pb.clone() - pa) / 2.0;
-    let pab_half = pa.clone() + vab_half;
-    ((pc.clone() - pab_half) * (1.0 / 3.0)) + pab_half
+impl<S: BaseFloat> Polyhedron<S> {
+    /// Builds a `TileLocator` over the tiles of a truncated polyhedron.
+    pub fn tile_locator(&self) -> TileLocator<S> {
+        let centers = (0..self.tile_count())
+            .map(|tile_index| self.tile_center(tile_index).normalize())
+            .collect();
+        let points = self
+            .positions
+            .iter()
+            .map(|position| position.0.normalize())
+            .collect();
+        TileLocator::new(
+            centers,
+            points,
+            self.tile_boundaries.clone(),
+            self.tile_neighbours.clone(),
+        )
+    }
 }
 
-fn find_center_of_triangles(
-    triangle_indices: &Vec<usize>,
-    triangle_centroids: &HashMap<usize, Vector3<f32>>,
-) -> Vector3<f32> {
-    let mut center_point: Vector3<f32> = Vector3::new(0.0, 0.0, 0.0);
+impl<S: BaseFloat> TileLocator<S> {
+    fn new(
+        centers: Vec<Vector3<S>>,
+        points: Vec<Vector3<S>>,
+        boundaries: Adjacency,
+        neighbours: Adjacency,
+    ) -> TileLocator<S> {
+        let resolution = ((centers.len() as f64 / 6.0).sqrt().ceil() as usize).max(1);
+        let mut locator = TileLocator {
+            centers,
+            points,
+            boundaries,
+            neighbours,
+            resolution,
+            seeds: Vec::with_capacity(6 * resolution * resolution),
+        };
+        if locator.centers.is_empty() {
+            return locator;
+        }
+
+        // Neighbouring cells are close together, so each walk starts from
+        // the previous cell's seed and only takes a step or two.
+        let mut seed = 0;
+        for face in 0..6 {
+            for row in 0..resolution {
+                for column in 0..resolution {
+                    let cell_middle = |index: usize| {
+                        scalar::<S>((2 * index + 1) as f64 / resolution as f64) - S::one()
+                    };
+                    let direction =
+                        cube_face_direction(face, cell_middle(column), cell_middle(row));
+                    seed = locator.nearest_center(seed, direction.normalize());
+                    locator.seeds.push(seed);
+                }
+            }
+        }
+        locator
+    }
+
+    /// Tile containing the point where `direction` leaves the sphere's
+    /// center; a point on the sphere works just as well. `None` if there are
+    /// no tiles or the direction is zero.
+    pub fn locate(&self, direction: Vector3<S>) -> Option<usize> {
+        if self.centers.is_empty() || direction.magnitude2() <= S::zero() {
+            return None;
+        }
+        let direction = direction.normalize();
+        let (face, u, v) = cube_face_coordinates(direction);
+        let cell = |coordinate: S| {
+            let scaled = (coordinate + S::one()) / scalar(2.0) * scalar(self.resolution as f64);
+            scaled.to_usize().unwrap_or(0).min(self.resolution - 1)
+        };
+        let seed = self.seeds[(face * self.resolution + cell(v)) * self.resolution + cell(u)];
+        let nearest = self.nearest_center(seed, direction);
+        Some(self.containing_tile(nearest, direction))
+    }
+
+    /// `locate` for a latitude and longitude in degrees.
+    pub fn locate_lat_lon(&self, latitude: S, longitude: S) -> Option<usize> {
+        self.locate(lat_lon_to_direction(latitude, longitude))
+    }
+
+    /// Walks to ever closer neighbours until no neighbour is closer to
+    /// `direction` than the current tile's center.
+    fn nearest_center(&self, start: usize, direction: Vector3<S>) -> usize {
+        let mut current = start;
+        let mut closeness = self.centers[current].dot(direction);
+        loop {
+            let closest_neighbour = self.neighbours[current]
+                .iter()
+                .map(|neighbour| *neighbour as usize)
+                .map(|neighbour| (neighbour, self.centers[neighbour].dot(direction)))
+                .fold(None, |best: Option<(usize, S)>, candidate| match best {
+                    Some(best) if best.1 >= candidate.1 => Some(best),
+                    _ => Some(candidate),
+                });
+            match closest_neighbour {
+                Some((neighbour, neighbour_closeness)) if neighbour_closeness > closeness => {
+                    current = neighbour;
+                    closeness = neighbour_closeness;
+                }
+                _ => return current,
+            }
+        }
+    }
+
+    /// Tile boundaries are not exactly the Voronoi cells of the centers, so
+    /// the nearest center can be one tile off near an edge: cross edges the
+    /// direction lies outside of until it is inside.
+    fn containing_tile(&self, start: usize, direction: Vector3<S>) -> usize {
+        let mut current = start;
+        for _ in 0..self.centers.len() {
+            let boundary = &self.boundaries[current];
+            let outside = (0..boundary.len()).find(|k| {
+                let corner = self.points[boundary[*k] as usize];
+                let next = self.points[boundary[(k + 1) % boundary.len()] as usize];
+                corner.cross(next).dot(direction) < S::zero()
+            });
+            match outside {
+                Some(k) => current = self.neighbours[current][k] as usize,
+                None => break,
+            }
+        }
+        current
+    }
+}
+
+/// Unit direction for a latitude and longitude in degrees, with +y through
+/// the north pole and longitude 0 along +x.
+pub fn lat_lon_to_direction<S: BaseFloat>(latitude: S, longitude: S) -> Vector3<S> {
+    let (latitude, longitude) = (latitude.to_radians(), longitude.to_radians());
+    Vector3::new(
+        latitude.cos() * longitude.cos(),
+        latitude.sin(),
+        -latitude.cos() * longitude.sin(),
+    )
+}
+
+/// Cube face a direction points through, with its coordinates on that face
+/// in [-1, 1].
+fn cube_face_coordinates<S: BaseFloat>(direction: Vector3<S>) -> (usize, S, S) {
+    let Vector3 { x, y, z } = direction;
+    let (ax, ay, az) = (x.abs(), y.abs(), z.abs());
+    let (axis, major, u, v) = if ax >= ay && ax >= az {
+        (0, x, y, z)
+    } else if ay >= az {
+        (1, y, z, x)
+    } else {
+        (2, z, x, y)
+    };
+    let face = if major < S::zero() { axis + 3 } else { axis };
+    (face, u / major.abs(), v / major.abs())
+}
+
+/// Inverse of `cube_face_coordinates`, up to length.
+fn cube_face_direction<S: BaseFloat>(face: usize, u: S, v: S) -> Vector3<S> {
+    let major = if face < 3 { S::one() } else { -S::one() };
+    match face % 3 {
+        0 => Vector3::new(major, u, v),
+        1 => Vector3::new(v, major, u),
+        _ => Vector3::new(u, v, major),
+    }
+}
+
+fn calculate_centroid<S: BaseFloat>(pa: Vector3<S>, pb: Vector3<S>, pc: Vector3<S>) -> Vector3<S> {
+    let vab_half = (pb - pa) / scalar(2.0);
+    let pab_half = pa + vab_half;
+    ((pc - pab_half) * scalar(1.0 / 3.0)) + pab_half
+}
+
+fn find_center_of_triangles<S: BaseFloat>(
+    triangle_indices: &[u32],
+    triangle_centroids: &[Vector3<S>],
+) -> Vector3<S> {
+    let mut center_point: Vector3<S> = Vector3::zero();
     for triangle_index in triangle_indices.iter() {
-        center_point += triangle_centroids[triangle_index];
+        center_point += triangle_centroids[*triangle_index as usize];
     }
-    center_point /= triangle_indices.len() as f32;
+    center_point /= scalar(triangle_indices.len() as f64);
     center_point
 }
+
+/// Area of the spherical triangle between three unit vectors
+/// (Van Oosterom and Strackee).
+fn spherical_triangle_area<S: BaseFloat>(a: Vector3<S>, b: Vector3<S>, c: Vector3<S>) -> S {
+    let triple = a.dot(b.cross(c)).abs();
+    let denominator = S::one() + a.dot(b) + b.dot(c) + c.dot(a);
+    triple.atan2(denominator) * scalar(2.0)
+}
+
+/// Converts a vector to the `f32` array that file formats and renderers take.
+fn to_f32<S: BaseFloat>(vector: Vector3<S>) -> [f32; 3] {
+    vector.cast::<f32>().unwrap().into()
+}
+
+/// A base solid and its opposite faces, built once per solid, for working
+/// out addresses on its lattice after generation.
+fn base_lattice(base: BaseSolid) -> &'static (Polyhedron<f64>, OppositeFaces) {
+    static BASES: OnceLock<Vec<(Polyhedron<f64>, OppositeFaces)>> = OnceLock::new();
+    let bases = BASES.get_or_init(|| {
+        BaseSolid::ALL
+            .into_iter()
+            .map(|base| {
+                let solid = Polyhedron::base_solid(base);
+                let opposite = solid.opposite_faces().expect("base solids are closed");
+                (solid, opposite)
+            })
+            .collect()
+    });
+    &bases[base as usize]
+}
+
+/// Address of lattice point (i, j) of base triangle `face`, which may lie
+/// up to a triangle beyond it.
+fn lattice_point_address<S>(lattice: &Lattice<S>, face: usize, i: i64, j: i64) -> TileAddress {
+    let (base, opposite) = base_lattice(lattice.base);
+    let (point_face, point_weights) = base.lattice_point(face, opposite, lattice.weights(i, j, 1));
+    let key = base.lattice_key(point_face, point_weights, lattice.edge_step());
+    base.lattice_address(key, lattice)
+}
+
+/// Edge frequency `2^detail` for the power-of-two constructors.
+fn frequency_for(detail: u32) -> Result<u32, PolyhedronError> {
+    2u32.checked_pow(detail)
+        .ok_or(PolyhedronError::InvalidDetail(detail))
+}
+
+/// Converts a constant into the polyhedron's scalar type.
+fn scalar<S: BaseFloat>(value: f64) -> S {
+    S::from(value).unwrap()
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
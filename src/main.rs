@@ -1,25 +1,25 @@
 use bevy::prelude::*;
 use std::collections::{BTreeMap, HashMap};
-use std::fs::File;
 use std::io::Write;
 use std::path::Path;
 use bevy::render::{
     camera::Camera,
-    mesh::Indices,
     RenderPlugin,
-    render_resource::PrimitiveTopology,
-    render_asset::RenderAssetUsages,
     settings::{Backends, RenderCreation, WgpuSettings},
 };
 use bevy::window::WindowTheme;
-use std::io::Read;
 use std::slice::Windows;
 use bevy::math::bounding::{RayCast2d, RayCast3d};
 use bevy::render::mesh::VertexAttributeValues;
 use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraPlugin};
-use rand::Rng;
-use serde::{Deserialize, Serialize};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 use bevy_mod_billboard::prelude::*;
+use web_hex::{Polyhedron, Tile, Tiles};
+
+const SPHERE_RADIUS: f32 = 5.0;
+// Same seed, same world.
+const WORLD_SEED: u64 = 42;
 
 // region from mouse to tile example
 #[derive(Debug, Resource)]
@@ -143,58 +143,27 @@ fn setup(mut commands: Commands,
     let mut map = BTreeMap::new();
 
     // Unity has 92 tiles: [ Unity_4:162, Unity_6:362, Unity_9:812, Unity_10:1002, Unity_20:4002 ]
-    println!("Read in our json file");
-    let mut file = File::open("unity.json").expect("file should open read only");
-    let mut data = String::new();
-    file.read_to_string(&mut data).unwrap();
-
-    // let json_data: serde_json::Value = serde_json::from_str(&data).expect("Unable to parse");
-    info!("Parse json to our object");
-    let p: Tiles = serde_json::from_str(&data).expect("Json data should conform to our object yo");
+    // Frequency n gives 10 * n^2 + 2 tiles, so 3 matches the 92 number textures.
+    info!("Generate the hexasphere tiles");
+    let polyhedron = Polyhedron::new_truncated_isocahedron_with_frequency(SPHERE_RADIUS, 3)
+        .expect("frequency 3 is a valid subdivision");
+    info!("{}", polyhedron.generation_stats());
+    let p: Tiles = polyhedron.to_tiles();
 
     // println!("{:?}", json_data);
     // println!("Object 0: {}\n has [0][1]: {}", json_data["tiles"][0], json_data["tiles"][0][1]);
     println!("Object 0: {:?}\n has [0][1]: {:?}", p.tiles[0], p.tiles[0].center_point);
     let fira_sans_regular_handle = asset_server.load("fonts/FiraCodeNerdFontPropo-Regular.ttf");
+    let mut ranr = ChaCha8Rng::seed_from_u64(WORLD_SEED);
 
     for tile in p.tiles {  // Vec<Tile>
-        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::RENDER_WORLD); //, RenderAssetUsages::new() 13.2
-
-        // points
-        let my_points: Vec<Vec3> = tile.boundary.iter().map(|b| Vec3::new(b.x, b.y, b.z)).collect();
-        // let my_points = vec![vec_points[x[0]], vec_points[x[1]], vec_points[x[2]], vec_points[x[3]], vec_points[x[4]]];
-
         // Center comes from tile
         let center = Vec3::new(tile.center_point.x, tile.center_point.y, tile.center_point.z);
 
-        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, my_points);
-        mesh.insert_indices(Indices::U32(tile.indices));
-
-        // Uvs came from Chat Gpt
-        let hex_uvs = if tile.is_hex {
-            vec![
-                [1.0, 0.5],
-                [0.75, 0.9330127],
-                [0.24999997, 0.9330127],
-                [0.0, 0.49999997],
-                [0.25000006, 0.066987276],
-                [0.74999994, 0.066987276]
-            ]
-        } else {
-            vec![
-                [1.0, 0.5],
-                [0.6545085, 0.97552824],
-                [0.09549147, 0.7938926],
-                [0.09549153, 0.20610732],
-                [0.6545086, 0.02447176]
-            ]
-        };
-
-        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, hex_uvs);
-        let mesh_handle = meshes.add(mesh);
+        // Positions, normals and polygon UVs for the number texture.
+        let mesh_handle = meshes.add(polyhedron.tile_mesh(tile.guid as usize));
 
         // Colors randomization
-        let mut ranr = rand::thread_rng();
         let random = &ranr.gen_range(0..4);
 
         // Srgba::hex("#ffd891").unwrap().into(),
@@ -434,38 +403,3 @@ impl TileObj {
         Self { id, biome }
     }
 }
-
-// Tile struct wrapper
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Tiles {
-    radius: u32,
-    tiles: Vec<Tile>,
-}
-
-// Tile struct
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Tile {
-    pub guid: u32,
-    pub center_point: Point,
-    pub is_hex: bool,
-    pub boundary: Vec<Point>,
-    pub indices: Vec<u32>,
-    pub neighbours: Vec<u32>,
-}
-
-// Point struct
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Point {
-    // pub guid: String,
-    // pub position: Vec3,
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
-}
-
-impl Point {
-    pub fn new(guid: String, x: f32, y: f32, z: f32) -> Self {
-        // Point {guid, x, y, z }
-        Point { x, y, z }
-    }
-}
@@ -0,0 +1,122 @@
+use std::f32::consts::TAU;
+
+use bevy::render::mesh::{Indices, Mesh, PrimitiveTopology};
+use bevy::render::render_asset::RenderAssetUsages;
+use cgmath::prelude::*;
+use cgmath::BaseFloat;
+
+use crate::{to_f32, Polyhedron};
+
+/// Vertex data of one or more tiles, gathered before building a `Mesh`.
+#[derive(Default)]
+struct MeshBuffers {
+    positions: Vec<[f32; 3]>,
+    normals: Vec<[f32; 3]>,
+    colors: Vec<[f32; 4]>,
+    uvs: Vec<[f32; 2]>,
+    indices: Vec<u32>,
+}
+
+impl MeshBuffers {
+    fn into_mesh(self) -> Mesh {
+        Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, self.positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, self.normals)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, self.colors)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, self.uvs)
+        .with_inserted_indices(Indices::U32(self.indices))
+    }
+}
+
+impl<S: BaseFloat> Polyhedron<S> {
+    /// Mesh of a single tile of a truncated polyhedron, in the polyhedron's
+    /// own coordinates. The tile is a fan around its center, flat shaded
+    /// along its direction from the sphere's center, in the colour of its
+    /// center vertex. UVs lay the tile out as a regular polygon filling the
+    /// unit square, corner 0 at (1, 0.5), to fit one texture per tile.
+    pub fn tile_mesh(&self, tile_index: usize) -> Mesh {
+        let mut buffers = MeshBuffers::default();
+        self.push_tile(tile_index, &mut buffers);
+        buffers.into_mesh()
+    }
+
+    /// `tile_mesh` for every tile, indexed like `tiles`.
+    pub fn tile_meshes(&self) -> Vec<Mesh> {
+        (0..self.tile_count())
+            .map(|tile_index| self.tile_mesh(tile_index))
+            .collect()
+    }
+
+    /// The whole polyhedron as one mesh. Truncated polyhedra put every tile
+    /// in as `tile_mesh` does; others use the triangles as they are, with
+    /// computed normals (or the direction from the center until computed)
+    /// and equirectangular UVs.
+    pub fn to_mesh(&self) -> Mesh {
+        let mut buffers = MeshBuffers::default();
+        if self.tile_count() > 0 {
+            for tile_index in 0..self.tile_count() {
+                self.push_tile(tile_index, &mut buffers);
+            }
+            return buffers.into_mesh();
+        }
+
+        for (position, normal) in self.positions.iter().zip(&self.normals) {
+            let direction = position.0.normalize();
+            let normal = if normal.0.magnitude2() > S::zero() {
+                normal.0.normalize()
+            } else {
+                direction
+            };
+            let direction = to_f32(direction);
+            buffers.positions.push(to_f32(position.0));
+            buffers.normals.push(to_f32(normal));
+            buffers.uvs.push([
+                0.5 + direction[2].atan2(direction[0]) / TAU,
+                0.5 - direction[1].asin() / TAU * 2.0,
+            ]);
+        }
+        buffers.colors = self
+            .colors
+            .iter()
+            .map(|color| [color.0.x, color.0.y, color.0.z, 1.0])
+            .collect();
+        buffers.indices = self.export_cells();
+        buffers.into_mesh()
+    }
+
+    fn push_tile(&self, tile_index: usize, buffers: &mut MeshBuffers) {
+        let tile = self.tile(tile_index);
+        let normal = to_f32(self.tile_center(tile_index).normalize());
+        let color = self.colors[tile.center as usize].0;
+        let first = buffers.positions.len() as u32;
+
+        buffers
+            .positions
+            .push(to_f32(self.positions[tile.center as usize].0));
+        buffers.uvs.push([0.5, 0.5]);
+        for (k, corner) in tile.boundary.iter().enumerate() {
+            let angle = TAU * k as f32 / tile.boundary.len() as f32;
+            buffers
+                .positions
+                .push(to_f32(self.positions[*corner as usize].0));
+            buffers
+                .uvs
+                .push([0.5 + 0.5 * angle.cos(), 0.5 + 0.5 * angle.sin()]);
+        }
+        let vertex_count = tile.boundary.len() + 1;
+        buffers.normals.extend(vec![normal; vertex_count]);
+        buffers
+            .colors
+            .extend(vec![[color.x, color.y, color.z, 1.0]; vertex_count]);
+
+        let corners = tile.boundary.len() as u32;
+        for k in 0..corners {
+            buffers
+                .indices
+                .extend([first, first + 1 + k, first + 1 + (k + 1) % corners]);
+        }
+    }
+}
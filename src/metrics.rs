@@ -0,0 +1,120 @@
+use cgmath::prelude::*;
+use cgmath::BaseFloat;
+use serde::Serialize;
+
+use crate::{scalar, Polyhedron};
+
+/// Shape measurements of a single tile, in the units of the sphere it sits on.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct TileMetrics<S = f32> {
+    /// Spherical area.
+    pub area: S,
+    /// Sum of the great-circle arcs along the boundary.
+    pub perimeter: S,
+    /// Largest angle, in radians, between the centre and a boundary corner.
+    pub angular_radius: S,
+    /// Coefficient of variation of the edge arcs plus that of the
+    /// centre-to-corner angles; 0 for a regular hexagon or pentagon.
+    pub irregularity: S,
+}
+
+/// Min, max, mean and population standard deviation of one metric.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct MetricSummary<S = f32> {
+    pub min: S,
+    pub max: S,
+    pub mean: S,
+    pub std_dev: S,
+}
+
+/// Every tile's metrics together with their aggregates.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MetricsReport<S = f32> {
+    pub tiles: Vec<TileMetrics<S>>,
+    pub area: MetricSummary<S>,
+    pub perimeter: MetricSummary<S>,
+    pub angular_radius: MetricSummary<S>,
+    pub irregularity: MetricSummary<S>,
+}
+
+impl<S: BaseFloat> MetricSummary<S> {
+    pub fn from_values(values: &[S]) -> MetricSummary<S> {
+        if values.is_empty() {
+            return MetricSummary {
+                min: S::zero(),
+                max: S::zero(),
+                mean: S::zero(),
+                std_dev: S::zero(),
+            };
+        }
+        let count = scalar::<S>(values.len() as f64);
+        let mean = values.iter().fold(S::zero(), |sum, value| sum + *value) / count;
+        let variance = values.iter().fold(S::zero(), |sum, value| {
+            sum + (*value - mean) * (*value - mean)
+        }) / count;
+        MetricSummary {
+            min: values
+                .iter()
+                .fold(S::infinity(), |min, value| min.min(*value)),
+            max: values
+                .iter()
+                .fold(S::neg_infinity(), |max, value| max.max(*value)),
+            mean,
+            std_dev: variance.sqrt(),
+        }
+    }
+}
+
+impl<S: BaseFloat> Polyhedron<S> {
+    /// Metrics for every tile of a truncated polyhedron, indexed like `tiles`,
+    /// measured on the polyhedron's sphere (see `radius`).
+    pub fn tile_metrics(&self) -> Vec<TileMetrics<S>> {
+        let radius = self.radius();
+        self.tile_areas()
+            .into_iter()
+            .enumerate()
+            .map(|(tile_index, unit_area)| {
+                let center = self.tile_center(tile_index).normalize();
+                let corners: Vec<_> = self
+                    .tile(tile_index)
+                    .boundary
+                    .iter()
+                    .map(|corner| self.positions[*corner as usize].0.normalize())
+                    .collect();
+
+                let edges: Vec<S> = (0..corners.len())
+                    .map(|k| corners[k].angle(corners[(k + 1) % corners.len()]).0)
+                    .collect();
+                let spokes: Vec<S> = corners
+                    .iter()
+                    .map(|corner| center.angle(*corner).0)
+                    .collect();
+                let edge_summary = MetricSummary::from_values(&edges);
+                let spoke_summary = MetricSummary::from_values(&spokes);
+
+                TileMetrics {
+                    area: unit_area * radius * radius,
+                    perimeter: edge_summary.mean * scalar(edges.len() as f64) * radius,
+                    angular_radius: spoke_summary.max,
+                    irregularity: edge_summary.std_dev / edge_summary.mean
+                        + spoke_summary.std_dev / spoke_summary.mean,
+                }
+            })
+            .collect()
+    }
+
+    /// `tile_metrics` plus the min/max/mean/standard deviation of each metric.
+    pub fn metrics_report(&self) -> MetricsReport<S> {
+        let tiles = self.tile_metrics();
+        let summarise = |metric: fn(&TileMetrics<S>) -> S| {
+            MetricSummary::from_values(&tiles.iter().map(metric).collect::<Vec<_>>())
+        };
+        MetricsReport {
+            area: summarise(|tile| tile.area),
+            perimeter: summarise(|tile| tile.perimeter),
+            angular_radius: summarise(|tile| tile.angular_radius),
+            irregularity: summarise(|tile| tile.irregularity),
+            tiles,
+        }
+    }
+}
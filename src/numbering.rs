@@ -0,0 +1,145 @@
+use crate::{base_lattice, BaseSolid, Lattice, TileAddress, VertexKey};
+
+/// How the vertices of a geodesic polyhedron, and so the tiles of its
+/// truncation, are numbered: the corners of the base solid first, then the
+/// points along each base edge in edge order, then the points inside each
+/// base triangle row by row. Every base triangle lays the lattice out the
+/// same way, so a table of where each row starts turns addresses into
+/// indices and back without storing either per tile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Numbering {
+    base: BaseSolid,
+    m: i64,
+    n: i64,
+    corners: usize,
+    /// Base edges as (lower, higher) vertex, sorted.
+    edges: Vec<(usize, usize)>,
+    /// Lattice points strictly inside each base edge.
+    edge_points: usize,
+    faces: usize,
+    /// First i inside the triangle on each row j, from j = 0.
+    row_starts: Vec<i64>,
+    /// Points inside the triangle before each row, plus the total.
+    row_offsets: Vec<usize>,
+}
+
+impl Numbering {
+    pub(crate) fn new<S>(lattice: &Lattice<S>) -> Numbering {
+        let (base, _) = base_lattice(lattice.base);
+        let mut edges: Vec<(usize, usize)> = base
+            .cells
+            .iter()
+            .map(|cell| cell.corners())
+            .flat_map(|[a, b, c]| [(a, b), (b, c), (c, a)])
+            .map(|(a, b)| (a.min(b), a.max(b)))
+            .collect();
+        edges.sort_unstable();
+        edges.dedup();
+
+        let (m, n) = (lattice.m, lattice.n);
+        let mut row_starts = vec![];
+        let mut row_offsets = vec![0];
+        for j in 0..=m + n {
+            let inside: Vec<i64> = (-n..=m)
+                .filter(|i| lattice.weights(*i, j, 1).iter().all(|w| *w > 0))
+                .collect();
+            row_starts.push(inside.first().copied().unwrap_or(0));
+            row_offsets.push(row_offsets[row_offsets.len() - 1] + inside.len());
+        }
+
+        Numbering {
+            base: lattice.base,
+            m,
+            n,
+            corners: base.positions.len(),
+            edges,
+            edge_points: (lattice.t() / lattice.edge_step() - 1) as usize,
+            faces: base.cells.len(),
+            row_starts,
+            row_offsets,
+        }
+    }
+
+    /// Number of vertices.
+    pub(crate) fn len(&self) -> usize {
+        self.corners + self.edges.len() * self.edge_points + self.faces * self.face_points()
+    }
+
+    fn face_points(&self) -> usize {
+        self.row_offsets[self.row_offsets.len() - 1]
+    }
+
+    fn lattice(&self) -> Lattice<()> {
+        Lattice {
+            base: self.base,
+            m: self.m,
+            n: self.n,
+            radius: (),
+            projection: Default::default(),
+        }
+    }
+
+    pub(crate) fn index(&self, key: VertexKey) -> usize {
+        match key {
+            VertexKey::Corner(vertex) => vertex,
+            VertexKey::Edge(low, high, step) => {
+                let edge = self.edges.binary_search(&(low, high)).unwrap();
+                self.corners + edge * self.edge_points + step as usize - 1
+            }
+            VertexKey::Face(face, u, v) => {
+                let (i, j) = self.lattice().coordinates(u, v);
+                let row = j as usize;
+                self.corners
+                    + self.edges.len() * self.edge_points
+                    + face * self.face_points()
+                    + self.row_offsets[row]
+                    + (i - self.row_starts[row]) as usize
+            }
+        }
+    }
+
+    pub(crate) fn key(&self, index: usize) -> VertexKey {
+        if index < self.corners {
+            return VertexKey::Corner(index);
+        }
+        let index = index - self.corners;
+        if index < self.edges.len() * self.edge_points {
+            let (low, high) = self.edges[index / self.edge_points];
+            return VertexKey::Edge(low, high, (index % self.edge_points) as i64 + 1);
+        }
+        let index = index - self.edges.len() * self.edge_points;
+        let (face, point) = (index / self.face_points(), index % self.face_points());
+        let row = self.row_offsets.partition_point(|offset| *offset <= point) - 1;
+        let i = self.row_starts[row] + (point - self.row_offsets[row]) as i64;
+        let weights = self.lattice().weights(i, row as i64, 1);
+        VertexKey::Face(face, weights[1], weights[2])
+    }
+
+    pub(crate) fn address(&self, index: usize) -> Option<TileAddress> {
+        let (base, _) = base_lattice(self.base);
+        (index < self.len()).then(|| base.lattice_address(self.key(index), &self.lattice()))
+    }
+
+    /// Index of the lattice point at `address`, if it is one: on an edge, it
+    /// must be given in the triangle that owns it.
+    pub(crate) fn address_index(&self, address: TileAddress) -> Option<usize> {
+        let (base, _) = base_lattice(self.base);
+        let key = match address {
+            TileAddress::Vertex(vertex) if vertex < self.corners => VertexKey::Corner(vertex),
+            TileAddress::Face { face, i, j } if face < self.faces => {
+                let weights = self.lattice().weights(i, j, 1);
+                if weights.iter().any(|w| *w < 0) {
+                    return None;
+                }
+                let lattice = self.lattice();
+                let key = base.lattice_key(face, weights, lattice.edge_step());
+                if base.lattice_address(key, &lattice) != address {
+                    return None;
+                }
+                key
+            }
+            _ => return None,
+        };
+        Some(self.index(key))
+    }
+}
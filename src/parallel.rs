@@ -0,0 +1,101 @@
+use std::num::NonZeroUsize;
+use std::thread;
+use std::time::Instant;
+
+use cgmath::BaseFloat;
+
+use crate::numbering::Numbering;
+use crate::{BaseSolid, Polyhedron, PolyhedronError, Projection};
+
+/// Multi-threaded generation. The independent work (subdividing each base
+/// triangle, projecting vertices, working out each tile) is shared between
+/// threads, and the results are numbered in the same order as the serial
+/// constructors, so the output is identical to theirs.
+impl<S: BaseFloat + Send + Sync> Polyhedron<S> {
+    /// `new_geodesic` on up to `threads` threads, or one per core when
+    /// `threads` is 0.
+    pub fn new_geodesic_parallel(
+        base: BaseSolid,
+        radius: S,
+        m: u32,
+        n: u32,
+        projection: Projection,
+        threads: usize,
+    ) -> Result<Polyhedron<S>, PolyhedronError> {
+        let start = Instant::now();
+        let (base, lattice, opposite) = Polyhedron::geodesic_base(base, radius, m, n, projection)?;
+        let face_triangles = map_indices(base.cells.len(), threads, |face_index| {
+            base.face_triangles(&opposite, face_index, &lattice)
+        });
+        let numbering = Numbering::new(&lattice);
+        let mut subdivided = Polyhedron::new();
+        subdivided.merge_triangles(&numbering, face_triangles);
+        let positions = map_indices(numbering.len(), threads, |k| {
+            base.lattice_position(numbering.key(k), &lattice)
+        });
+        subdivided.finish_subdivision(lattice, numbering, positions, start);
+        Ok(subdivided)
+    }
+
+    /// `new_truncated_geodesic` on up to `threads` threads, or one per core
+    /// when `threads` is 0.
+    pub fn new_truncated_geodesic_parallel(
+        base: BaseSolid,
+        radius: S,
+        m: u32,
+        n: u32,
+        projection: Projection,
+        threads: usize,
+    ) -> Result<Polyhedron<S>, PolyhedronError> {
+        Polyhedron::new_geodesic_parallel(base, radius, m, n, projection, threads)?
+            .into_truncated_parallel(threads)
+    }
+
+    /// `into_truncated` on up to `threads` threads, or one per core when
+    /// `threads` is 0.
+    pub fn into_truncated_parallel(self, threads: usize) -> Result<Polyhedron<S>, PolyhedronError> {
+        let start = Instant::now();
+        self.check_surface()?;
+        let vert_to_faces = self.vert_to_faces();
+        let triangle_centroids = self.triangle_centroids();
+        let plans = map_indices(self.positions.len(), threads, |i| {
+            self.plan_tile(i, &vert_to_faces, &triangle_centroids)
+        });
+        let mut shared = self.shared_vertices();
+        let mut truncated = Polyhedron::new();
+        for plan in plans {
+            truncated.add_tile(plan?, &triangle_centroids, &mut shared);
+        }
+        truncated.finish_truncation(self, start);
+        Ok(truncated)
+    }
+}
+
+/// Maps `f` over `0..count` with each of up to `threads` scoped threads
+/// taking a contiguous run of indices, and returns the results in index
+/// order. Runs on the calling thread when one thread is enough.
+fn map_indices<T: Send>(count: usize, threads: usize, f: impl Fn(usize) -> T + Sync) -> Vec<T> {
+    let threads = match threads {
+        0 => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        threads => threads,
+    }
+    .min(count);
+    if threads <= 1 {
+        return (0..count).map(f).collect();
+    }
+
+    let chunk = count.div_ceil(threads);
+    let f = &f;
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..count)
+            .step_by(chunk)
+            .map(|first| {
+                scope.spawn(move || (first..count.min(first + chunk)).map(f).collect::<Vec<_>>())
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    })
+}
@@ -0,0 +1,128 @@
+use cgmath::prelude::*;
+use cgmath::{BaseFloat, Vector3};
+use serde::{Deserialize, Serialize};
+
+use crate::scalar;
+
+/// How points laid out on a flat base triangle are moved onto the sphere.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Projection {
+    /// Interpolate on the flat triangle, then push the point out to the
+    /// sphere. Tiles near the base vertices come out noticeably smaller than
+    /// tiles at the face centres.
+    #[default]
+    Gnomonic,
+    /// Interpolate along great circles, so lattice points are evenly spaced
+    /// along every base edge.
+    Slerp,
+    /// Area preserving: splits each base triangle into six right triangles
+    /// around its centre and maps each one onto its spherical counterpart
+    /// with Arvo's equal-area parameterisation, in the spirit of Snyder's
+    /// polyhedral projection.
+    EqualArea,
+}
+
+impl Projection {
+    /// Projects the point with barycentric `weights` (summing to `t`) on the
+    /// triangle `corners` onto the unit sphere.
+    pub(crate) fn project<S: BaseFloat>(
+        self,
+        corners: [Vector3<S>; 3],
+        weights: [i64; 3],
+        t: i64,
+    ) -> Vector3<S> {
+        let weights = weights.map(|w| scalar::<S>(w as f64) / scalar(t as f64));
+        match self {
+            Projection::Gnomonic => {
+                (corners[0] * weights[0] + corners[1] * weights[1] + corners[2] * weights[2])
+                    .normalize()
+            }
+            Projection::Slerp => {
+                let [a, b, c] = corners.map(|corner| corner.normalize());
+                let ab_weight = weights[0] + weights[1];
+                if ab_weight <= S::zero() {
+                    return c;
+                }
+                let on_ab = slerp(a, b, weights[1] / ab_weight);
+                slerp(on_ab, c, weights[2])
+            }
+            Projection::EqualArea => {
+                let corners = corners.map(|corner| corner.normalize());
+                let mut order = [0, 1, 2];
+                order.sort_by(|i, j| weights[*j].partial_cmp(&weights[*i]).unwrap());
+                let [x, y, z] = order;
+
+                // Planar weights of the point in the right triangle formed by
+                // the nearest corner, the face centre and the nearest edge's
+                // midpoint.
+                let on_corner = weights[x] - weights[y];
+                let on_center = weights[z] * scalar(3.0);
+                let on_midpoint = (weights[y] - weights[z]) * scalar(2.0);
+
+                let center = (corners[0] + corners[1] + corners[2]).normalize();
+                let midpoint = (corners[x] + corners[y]).normalize();
+                debug_assert!(on_corner + on_center + on_midpoint > S::zero());
+                equal_area(corners[x], center, midpoint, on_corner, on_midpoint)
+            }
+        }
+    }
+}
+
+fn slerp<S: BaseFloat>(from: Vector3<S>, to: Vector3<S>, amount: S) -> Vector3<S> {
+    let angle = from.angle(to).0;
+    if angle.sin() <= S::default_epsilon() {
+        return from.lerp(to, amount).normalize();
+    }
+    (from * ((S::one() - amount) * angle).sin() + to * (amount * angle).sin()) / angle.sin()
+}
+
+/// Maps planar barycentric weights on the triangle (a, b, c) to the point of
+/// the spherical triangle (a, b, c) with the same share of area, following
+/// Arvo's "Stratified Sampling of Spherical Triangles" with `b` as the apex.
+/// The apex takes whatever weight `on_a` and `on_c` leave over.
+fn equal_area<S: BaseFloat>(
+    a: Vector3<S>,
+    b: Vector3<S>,
+    c: Vector3<S>,
+    on_a: S,
+    on_c: S,
+) -> Vector3<S> {
+    let along_ac = on_a + on_c;
+    if along_ac <= S::default_epsilon() {
+        return b;
+    }
+    let area_share = on_c / along_ac;
+    let radial_share = along_ac * along_ac;
+
+    // Find the point on arc ac that cuts off `area_share` of the area.
+    let alpha = corner_angle(a, b, c);
+    let area = alpha + corner_angle(b, c, a) + corner_angle(c, a, b) - scalar(std::f64::consts::PI);
+    let sub_area = area_share * area;
+    let (s, t) = (sub_area - alpha).sin_cos();
+    let u = t - alpha.cos();
+    let v = s + alpha.sin() * a.dot(b);
+    let q = ((v * t - u * s) * alpha.cos() - v) / ((v * s + u * t) * alpha.sin());
+    let q = q.min(S::one()).max(-S::one());
+    let on_ac = a * q + tangent(a, c) * (S::one() - q * q).sqrt();
+
+    // Then walk from the apex towards it.
+    let z = S::one() - radial_share * (S::one() - on_ac.dot(b));
+    let z = z.min(S::one()).max(-S::one());
+    b * z + tangent(b, on_ac) * (S::one() - z * z).sqrt()
+}
+
+/// Unit vector at `from` pointing along the great circle towards `to`.
+fn tangent<S: BaseFloat>(from: Vector3<S>, to: Vector3<S>) -> Vector3<S> {
+    let along = to - from * to.dot(from);
+    if along.magnitude2() <= S::default_epsilon() * S::default_epsilon() {
+        return Vector3::zero();
+    }
+    along.normalize()
+}
+
+/// Interior angle at `at` of the spherical triangle (at, p, q).
+fn corner_angle<S: BaseFloat>(at: Vector3<S>, p: Vector3<S>, q: Vector3<S>) -> S {
+    let to_p = tangent(at, p);
+    let to_q = tangent(at, q);
+    to_p.cross(to_q).magnitude().atan2(to_p.dot(to_q))
+}
@@ -0,0 +1,65 @@
+use cgmath::prelude::*;
+use cgmath::{BaseFloat, Vector3};
+
+use crate::{spherical_triangle_area, ArraySerializedVector, Polyhedron, PolyhedronError};
+
+impl<S: BaseFloat> Polyhedron<S> {
+    /// Spherical Lloyd relaxation of an untruncated geodesic polyhedron: each
+    /// vertex moves to the area centroid of the tile that will surround it
+    /// once truncated. Connectivity is left alone, so the 12 pentagons and all
+    /// adjacency survive while the hexagons become more regular. Truncate
+    /// afterwards with `into_truncated` to rebuild the tile boundaries.
+    ///
+    /// Stops after `iterations` passes, or earlier once no vertex moves by more
+    /// than `tolerance` times its distance from the origin. Returns the number
+    /// of passes made, or an error if the triangles do not form a closed
+    /// surface.
+    pub fn relax(&mut self, iterations: usize, tolerance: S) -> Result<usize, PolyhedronError> {
+        self.check_surface()?;
+        let vert_to_faces = self.vert_to_faces();
+        let triangle_centroids = self.triangle_centroids();
+        let rings = (0..self.positions.len())
+            .map(|i| self.ordered_ring(i, &vert_to_faces[i], &triangle_centroids))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for iteration in 0..iterations {
+            let corners: Vec<Vector3<S>> = self
+                .cells
+                .iter()
+                .map(|cell| {
+                    let [a, b, c] = cell.corners().map(|corner| self.positions[corner].0);
+                    (a + b + c).normalize()
+                })
+                .collect();
+
+            let mut largest_move = S::zero();
+            let relaxed: Vec<Vector3<S>> = rings
+                .iter()
+                .enumerate()
+                .map(|(i, ring)| {
+                    let position = self.positions[i].0;
+                    let center = position.normalize();
+                    let mut centroid = Vector3::zero();
+                    for k in 0..ring.len() {
+                        let a = corners[ring[k]];
+                        let b = corners[ring[(k + 1) % ring.len()]];
+                        centroid +=
+                            (center + a + b).normalize() * spherical_triangle_area(center, a, b);
+                    }
+                    let moved = centroid.normalize() * position.magnitude();
+                    largest_move =
+                        largest_move.max((moved - position).magnitude() / position.magnitude());
+                    moved
+                })
+                .collect();
+
+            for (position, moved) in self.positions.iter_mut().zip(relaxed) {
+                *position = ArraySerializedVector(moved);
+            }
+            if largest_move <= tolerance {
+                return Ok(iteration + 1);
+            }
+        }
+        Ok(iterations)
+    }
+}
@@ -0,0 +1,227 @@
+use cgmath::prelude::*;
+use cgmath::{BaseFloat, Vector3};
+use serde::de::{self, Deserializer};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+
+use crate::numbering::Numbering;
+use crate::{Adjacency, ArraySerializedVector, Lattice, Polyhedron, Triangle};
+
+/// The serialized form of a polyhedron. Everything but the positions and
+/// triangles may be left out, as in files written before they existed.
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "S: Deserialize<'de>"))]
+pub(crate) struct PolyhedronData<S> {
+    pub(crate) positions: Vec<ArraySerializedVector<S>>,
+    pub(crate) cells: Vec<Triangle>,
+    #[serde(default)]
+    pub(crate) normals: Vec<ArraySerializedVector<S>>,
+    #[serde(default)]
+    pub(crate) colors: Vec<ArraySerializedVector>,
+    #[serde(default)]
+    pub(crate) faces: Option<Adjacency>,
+    #[serde(default)]
+    pub(crate) tiles: TileRows,
+    #[serde(default)]
+    pub(crate) lattice: Option<Lattice<S>>,
+}
+
+/// The tiles of a polyhedron, in the flat layout `Polyhedron` keeps them in.
+#[derive(Default)]
+pub(crate) struct TileRows {
+    pub(crate) centers: Vec<u32>,
+    pub(crate) boundaries: Adjacency,
+    pub(crate) neighbours: Adjacency,
+}
+
+#[derive(Deserialize)]
+struct TileData {
+    center: u32,
+    boundary: Vec<u32>,
+    neighbours: Vec<u32>,
+}
+
+/// Reads the list of tiles written by `Polyhedron`'s `Serialize`.
+impl<'de> Deserialize<'de> for TileRows {
+    fn deserialize<D>(deserializer: D) -> Result<TileRows, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut rows = TileRows::default();
+        for tile in Vec::<TileData>::deserialize(deserializer)? {
+            rows.centers.push(tile.center);
+            rows.boundaries.push_row(tile.boundary);
+            rows.neighbours.push_row(tile.neighbours);
+        }
+        Ok(rows)
+    }
+}
+
+impl<S: BaseFloat + Serialize> Serialize for Polyhedron<S> {
+    fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error>
+    where
+        T: Serializer,
+    {
+        let has_tiles = self.tile_count() > 0;
+        let fields = 5 + has_tiles as usize + self.lattice.is_some() as usize;
+        let mut state = serializer.serialize_struct("Polyhedron", fields)?;
+        state.serialize_field("positions", &self.positions)?;
+        state.serialize_field("cells", &self.cells)?;
+        state.serialize_field("normals", &self.normals)?;
+        state.serialize_field("colors", &self.colors)?;
+        state.serialize_field("faces", &self.faces)?;
+        if has_tiles {
+            state.serialize_field("tiles", &self.tiles().collect::<Vec<_>>())?;
+        }
+        if let Some(lattice) = &self.lattice {
+            state.serialize_field("lattice", lattice)?;
+        }
+        state.end()
+    }
+}
+
+impl<'de, S: BaseFloat + Deserialize<'de>> Deserialize<'de> for Polyhedron<S> {
+    fn deserialize<D>(deserializer: D) -> Result<Polyhedron<S>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let data = PolyhedronData::deserialize(deserializer)?;
+        Polyhedron::from_data(data).map_err(de::Error::custom)
+    }
+}
+
+impl<S: BaseFloat> Polyhedron<S> {
+    /// Checks that every index in `data` points at something that exists,
+    /// then rebuilds what is not saved: addresses from the lattice and the
+    /// counts in the generation stats.
+    pub(crate) fn from_data(data: PolyhedronData<S>) -> Result<Polyhedron<S>, String> {
+        let vertex_count = data.positions.len();
+        let mut polyhedron = Polyhedron::new();
+        polyhedron.positions = data.positions;
+        polyhedron.cells = data.cells;
+        polyhedron.normals = data.normals;
+        polyhedron.colors = data.colors;
+        if polyhedron.normals.is_empty() {
+            polyhedron.normals = (0..vertex_count)
+                .map(|_| ArraySerializedVector(Vector3::zero()))
+                .collect();
+        }
+        if polyhedron.colors.is_empty() {
+            polyhedron.colors = (0..vertex_count)
+                .map(|_| ArraySerializedVector(Vector3::new(1.0, 1.0, 1.0)))
+                .collect();
+        }
+        if polyhedron.normals.len() != vertex_count || polyhedron.colors.len() != vertex_count {
+            return Err(format!(
+                "{vertex_count} positions but {} normals and {} colors",
+                polyhedron.normals.len(),
+                polyhedron.colors.len()
+            ));
+        }
+        for (cell_index, cell) in polyhedron.cells.iter().enumerate() {
+            if let Some(vertex) = cell
+                .corners()
+                .into_iter()
+                .find(|vertex| *vertex >= vertex_count)
+            {
+                return Err(format!(
+                    "cell {cell_index} refers to missing vertex {vertex}"
+                ));
+            }
+        }
+
+        polyhedron.faces = data
+            .faces
+            .unwrap_or_else(|| Adjacency::identity(polyhedron.cells.len()));
+        if let Some(cell) = polyhedron
+            .faces
+            .targets()
+            .iter()
+            .find(|cell| **cell as usize >= polyhedron.cells.len())
+        {
+            return Err(format!("a face refers to missing cell {cell}"));
+        }
+
+        let tiles = data.tiles;
+        let tile_count = tiles.centers.len();
+        if tiles.boundaries.len() != tile_count || tiles.neighbours.len() != tile_count {
+            return Err(format!(
+                "{tile_count} tile centers but {} boundaries and {} neighbour lists",
+                tiles.boundaries.len(),
+                tiles.neighbours.len()
+            ));
+        }
+        if tile_count > 0 && polyhedron.faces.len() != tile_count {
+            return Err(format!(
+                "{tile_count} tiles but {} faces",
+                polyhedron.faces.len()
+            ));
+        }
+        for (tile_index, (boundary, neighbours)) in tiles
+            .boundaries
+            .iter()
+            .zip(tiles.neighbours.iter())
+            .enumerate()
+        {
+            if boundary.len() != neighbours.len()
+                || boundary.len() < 3
+                || tiles.centers[tile_index] as usize >= vertex_count
+                || boundary
+                    .iter()
+                    .any(|corner| *corner as usize >= vertex_count)
+                || neighbours
+                    .iter()
+                    .any(|neighbour| *neighbour as usize >= tile_count)
+            {
+                return Err(format!("tile {tile_index} is malformed"));
+            }
+            match boundary.len() {
+                6 => polyhedron.stats.hexagons += 1,
+                5 => polyhedron.stats.pentagons += 1,
+                _ => polyhedron.stats.other_tiles += 1,
+            }
+        }
+        polyhedron.tile_centers = tiles.centers;
+        polyhedron.tile_boundaries = tiles.boundaries;
+        polyhedron.tile_neighbours = tiles.neighbours;
+
+        if let Some(lattice) = data.lattice {
+            polyhedron.restore_addresses(lattice)?;
+        }
+        polyhedron.stats.vertices = vertex_count;
+        polyhedron.stats.triangles = polyhedron.cells.len();
+        Ok(polyhedron)
+    }
+
+    /// Numbers the tiles, or vertices before truncation, on `lattice`, which
+    /// gives every one its address, checking that it has as many points.
+    fn restore_addresses(&mut self, lattice: Lattice<S>) -> Result<(), String> {
+        let (m, n) = match (u32::try_from(lattice.m), u32::try_from(lattice.n)) {
+            (Ok(m), Ok(n)) => (m, n),
+            _ => {
+                return Err(format!(
+                    "GP({}, {}) is not a usable subdivision",
+                    lattice.m, lattice.n
+                ))
+            }
+        };
+        let (_, lattice, _) =
+            Polyhedron::geodesic_base(lattice.base, lattice.radius, m, n, lattice.projection)
+                .map_err(|error| error.to_string())?;
+        let numbering = Numbering::new(&lattice);
+        let addressed = if self.tile_count() > 0 {
+            self.tile_count()
+        } else {
+            self.positions.len()
+        };
+        if numbering.len() != addressed {
+            return Err(format!(
+                "the lattice has {} points but the polyhedron has {addressed} tiles or vertices",
+                numbering.len()
+            ));
+        }
+        self.lattice = Some(lattice);
+        self.numbering = Some(numbering);
+        Ok(())
+    }
+}
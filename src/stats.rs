@@ -0,0 +1,45 @@
+use std::fmt;
+use std::time::Duration;
+
+use serde::Serialize;
+
+/// What went into generating a polyhedron, for callers that want to log or
+/// profile it. Generation itself prints nothing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct GenerationStats {
+    /// Six sided tiles; zero before truncation.
+    pub hexagons: usize,
+    /// Five sided tiles; zero before truncation.
+    pub pentagons: usize,
+    /// Tiles with any other number of sides, such as the squares and
+    /// triangles of truncated octahedra and tetrahedra.
+    pub other_tiles: usize,
+    pub vertices: usize,
+    pub triangles: usize,
+    /// Triangle or tile corners that reuse a vertex already added for a
+    /// neighbour rather than adding their own, summed over subdivision and
+    /// truncation. Subdivision shares every vertex it can, so it contributes
+    /// 3 × triangles − vertices.
+    pub shared_corners: usize,
+    /// Wall-clock time spent generating, summed over subdivision and
+    /// truncation.
+    pub duration: Duration,
+}
+
+impl fmt::Display for GenerationStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "hexagons: {}, pentagons: {}",
+            self.hexagons, self.pentagons
+        )?;
+        if self.other_tiles > 0 {
+            write!(f, ", other tiles: {}", self.other_tiles)?;
+        }
+        write!(
+            f,
+            ", vertices: {}, triangles: {}, shared corners: {}, generated in {:?}",
+            self.vertices, self.triangles, self.shared_corners, self.duration
+        )
+    }
+}
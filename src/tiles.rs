@@ -0,0 +1,90 @@
+use cgmath::{BaseFloat, Vector3};
+use serde::{Deserialize, Serialize};
+
+use crate::Polyhedron;
+
+// Tile struct wrapper
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tiles {
+    pub radius: f32,
+    pub tiles: Vec<Tile>,
+}
+
+// Tile struct
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tile {
+    pub guid: u32,
+    pub center_point: Point,
+    pub is_hex: bool,
+    pub boundary: Vec<Point>,
+    pub indices: Vec<u32>,
+    pub neighbours: Vec<u32>,
+}
+
+// Point struct
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Point {
+    // pub guid: String,
+    // pub position: Vec3,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Point {
+    pub fn new(_guid: String, x: f32, y: f32, z: f32) -> Self {
+        // Point {guid, x, y, z }
+        Point { x, y, z }
+    }
+}
+
+impl From<Vector3<f32>> for Point {
+    fn from(vector: Vector3<f32>) -> Self {
+        Point {
+            x: vector.x,
+            y: vector.y,
+            z: vector.z,
+        }
+    }
+}
+
+impl<S: BaseFloat> Polyhedron<S> {
+    /// Converts a truncated polyhedron (see `new_truncated_isocahedron`) into the
+    /// `Tiles` model used by the renderer, one `Tile` per hexagon or pentagon.
+    /// Points are always `f32`, whatever the polyhedron's scalar type, and
+    /// the radius is the polyhedron's own.
+    pub fn to_tiles(&self) -> Tiles {
+        let tiles = self
+            .tiles()
+            .enumerate()
+            .map(|(tile_index, topology)| {
+                let mut indices = vec![];
+                for i in 1..topology.boundary.len() as u32 - 1 {
+                    indices.extend([0, i, i + 1]);
+                }
+
+                Tile {
+                    guid: tile_index as u32,
+                    center_point: to_point(self.tile_center(tile_index)),
+                    is_hex: topology.is_hex(),
+                    boundary: topology
+                        .boundary
+                        .iter()
+                        .map(|corner| to_point(self.positions[*corner as usize].0))
+                        .collect(),
+                    indices,
+                    neighbours: topology.neighbours.to_vec(),
+                }
+            })
+            .collect();
+
+        Tiles {
+            radius: self.radius().to_f32().unwrap(),
+            tiles,
+        }
+    }
+}
+
+fn to_point<S: BaseFloat>(vector: Vector3<S>) -> Point {
+    Point::from(vector.cast::<f32>().unwrap())
+}
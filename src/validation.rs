@@ -0,0 +1,306 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use cgmath::prelude::*;
+use cgmath::{BaseFloat, Vector3};
+use serde::Serialize;
+
+use crate::{Adjacency, BaseSolid, Polyhedron, Tiles};
+
+/// A way in which a polyhedron or tile set is not a closed, outward facing
+/// sphere, as found by `Polyhedron::validate` and `Tiles::validate`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum ValidationIssue {
+    /// The edge between two vertices is used by `triangles` triangles
+    /// rather than two.
+    OpenEdge {
+        a: usize,
+        b: usize,
+        triangles: usize,
+    },
+    /// A triangle wound clockwise seen from outside the sphere.
+    InwardTriangle(usize),
+    /// The edge from boundary corner `corner` of a tile to the next one is
+    /// used by `tiles` tiles rather than two.
+    OpenTileEdge {
+        tile: usize,
+        corner: usize,
+        tiles: usize,
+    },
+    /// A tile with a triangle wound clockwise seen from outside the sphere,
+    /// or one that refers to a corner the tile does not have.
+    InwardTile(usize),
+    /// V - E + F is not 2.
+    EulerCharacteristic(i64),
+    /// The tiles that are not hexagons are not those of the base solid: 12
+    /// pentagons for an icosahedron, 6 squares for an octahedron or 4
+    /// triangles for a tetrahedron, and no tile with more than 6 sides.
+    /// Lists every tile that is not a hexagon.
+    NonHexagons(Vec<usize>),
+    /// `neighbour` is listed as a neighbour of `tile` but does not list it
+    /// back.
+    AsymmetricNeighbours { tile: usize, neighbour: usize },
+    /// `tile` lists a neighbour by a guid that no tile has.
+    UnknownNeighbour { tile: usize, guid: u32 },
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationIssue::OpenEdge { a, b, triangles } => {
+                write!(f, "edge {a}-{b} is shared by {triangles} triangles")
+            }
+            ValidationIssue::InwardTriangle(triangle) => {
+                write!(f, "triangle {triangle} faces inwards")
+            }
+            ValidationIssue::OpenTileEdge {
+                tile,
+                corner,
+                tiles,
+            } => {
+                write!(f, "edge {corner} of tile {tile} is shared by {tiles} tiles")
+            }
+            ValidationIssue::InwardTile(tile) => {
+                write!(f, "tile {tile} has a triangle facing inwards")
+            }
+            ValidationIssue::EulerCharacteristic(euler) => {
+                write!(f, "V - E + F is {euler} rather than 2")
+            }
+            ValidationIssue::NonHexagons(tiles) => {
+                write!(f, "{} tiles are not hexagons: {tiles:?}", tiles.len())
+            }
+            ValidationIssue::AsymmetricNeighbours { tile, neighbour } => {
+                write!(
+                    f,
+                    "tile {tile} neighbours tile {neighbour} but not the other way round"
+                )
+            }
+            ValidationIssue::UnknownNeighbour { tile, guid } => {
+                write!(f, "tile {tile} neighbours guid {guid}, which no tile has")
+            }
+        }
+    }
+}
+
+impl<S: BaseFloat> Polyhedron<S> {
+    /// Checks that every edge is shared by exactly two triangles, that every
+    /// triangle winds anticlockwise seen from outside, that V - E + F = 2
+    /// and, once truncated, that the non-hexagonal tiles are those of the
+    /// base solid and that neighbours list each other. Returns every
+    /// problem found.
+    pub fn validate(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut issues = vec![];
+        let triangles = self.cells.iter().map(|cell| cell.corners());
+        let edges = edge_counts(triangles.clone());
+        let mut open_edges: Vec<_> = edges
+            .iter()
+            .filter(|(_, triangles)| **triangles != 2)
+            .collect();
+        open_edges.sort();
+        for (&(a, b), &triangles) in open_edges {
+            issues.push(ValidationIssue::OpenEdge { a, b, triangles });
+        }
+        for (triangle_index, corners) in triangles.enumerate() {
+            let [a, b, c] = corners.map(|i| self.positions.get(i).map(|p| p.0));
+            if !matches!((a, b, c), (Some(a), Some(b), Some(c)) if faces_outwards(a, b, c)) {
+                issues.push(ValidationIssue::InwardTriangle(triangle_index));
+            }
+        }
+        check_euler(
+            self.positions.len(),
+            edges.len(),
+            self.cells.len(),
+            &mut issues,
+        );
+        if self.tile_count() > 0 {
+            let bases = match self.lattice {
+                Some(lattice) => vec![lattice.base],
+                None => BaseSolid::ALL.to_vec(),
+            };
+            check_tiles(
+                self.tiles().map(|tile| tile.boundary.len()),
+                &bases,
+                self.tile_neighbours(),
+                &mut issues,
+            );
+        }
+        finish(issues)
+    }
+}
+
+impl Tiles {
+    /// The checks of `Polyhedron::validate` on tiles alone, as loaded from
+    /// a file: every boundary edge is shared by exactly two tiles, every
+    /// triangle in `indices` winds anticlockwise seen from outside, V - E + F
+    /// = 2 counting tiles as faces, the non-hexagonal tiles are those of one
+    /// of the base solids and neighbours list each other by known guids.
+    /// Corners are matched by position.
+    pub fn validate(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut issues = vec![];
+        let mut vertices = HashMap::new();
+        let corners: Vec<Vec<usize>> = self
+            .tiles
+            .iter()
+            .map(|tile| {
+                tile.boundary
+                    .iter()
+                    .map(|point| {
+                        let count = vertices.len();
+                        *vertices
+                            .entry([point.x, point.y, point.z].map(f32::to_bits))
+                            .or_insert(count)
+                    })
+                    .collect()
+            })
+            .collect();
+        let edges = edge_counts(corners.iter().map(|corners| corners.as_slice()));
+        for (tile_index, corners) in corners.iter().enumerate() {
+            for corner in 0..corners.len() {
+                let (a, b) = (corners[corner], corners[(corner + 1) % corners.len()]);
+                let tiles = edges[&(a.min(b), a.max(b))];
+                if tiles != 2 {
+                    issues.push(ValidationIssue::OpenTileEdge {
+                        tile: tile_index,
+                        corner,
+                        tiles,
+                    });
+                }
+            }
+        }
+        for (tile_index, tile) in self.tiles.iter().enumerate() {
+            let points: Vec<_> = tile
+                .boundary
+                .iter()
+                .map(|point| Vector3::new(point.x, point.y, point.z))
+                .collect();
+            let outward = tile.indices.chunks(3).all(|triangle| {
+                match triangle
+                    .iter()
+                    .map(|i| points.get(*i as usize))
+                    .collect::<Option<Vec<_>>>()
+                    .as_deref()
+                {
+                    Some([a, b, c]) => faces_outwards(**a, **b, **c),
+                    _ => false,
+                }
+            });
+            if !outward {
+                issues.push(ValidationIssue::InwardTile(tile_index));
+            }
+        }
+        check_euler(vertices.len(), edges.len(), self.tiles.len(), &mut issues);
+
+        let indices: HashMap<u32, usize> = self
+            .tiles
+            .iter()
+            .enumerate()
+            .map(|(tile_index, tile)| (tile.guid, tile_index))
+            .collect();
+        let mut neighbours = Adjacency::new();
+        for (tile_index, tile) in self.tiles.iter().enumerate() {
+            let mut row = vec![];
+            for &guid in &tile.neighbours {
+                match indices.get(&guid) {
+                    Some(neighbour) => row.push(*neighbour as u32),
+                    None => issues.push(ValidationIssue::UnknownNeighbour {
+                        tile: tile_index,
+                        guid,
+                    }),
+                }
+            }
+            neighbours.push_row(row);
+        }
+        check_tiles(
+            self.tiles.iter().map(|tile| tile.boundary.len()),
+            &BaseSolid::ALL,
+            &neighbours,
+            &mut issues,
+        );
+        finish(issues)
+    }
+}
+
+/// How many polygons use each undirected edge of the given polygons.
+fn edge_counts<P: AsRef<[usize]>>(
+    polygons: impl Iterator<Item = P>,
+) -> HashMap<(usize, usize), usize> {
+    let mut counts = HashMap::new();
+    for polygon in polygons {
+        let corners = polygon.as_ref();
+        for k in 0..corners.len() {
+            let (a, b) = (corners[k], corners[(k + 1) % corners.len()]);
+            *counts.entry((a.min(b), a.max(b))).or_default() += 1;
+        }
+    }
+    counts
+}
+
+/// Whether the triangle winds anticlockwise seen from outside a sphere
+/// centred on the origin.
+fn faces_outwards<S: BaseFloat>(a: Vector3<S>, b: Vector3<S>, c: Vector3<S>) -> bool {
+    (b - a).cross(c - a).dot(a + b + c) > S::zero()
+}
+
+fn check_euler(vertices: usize, edges: usize, faces: usize, issues: &mut Vec<ValidationIssue>) {
+    let euler = vertices as i64 - edges as i64 + faces as i64;
+    if euler != 2 {
+        issues.push(ValidationIssue::EulerCharacteristic(euler));
+    }
+}
+
+/// Checks that the tiles that are not hexagons are exactly the corner
+/// tiles of one of `bases`, and that every neighbour lists its tile back.
+fn check_tiles(
+    sides: impl Iterator<Item = usize>,
+    bases: &[BaseSolid],
+    neighbours: &Adjacency,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    let mut non_hexagons = vec![];
+    let mut side_counts = HashMap::new();
+    for (tile_index, sides) in sides.enumerate() {
+        if sides != 6 {
+            non_hexagons.push(tile_index);
+            *side_counts.entry(sides).or_insert(0) += 1;
+        }
+    }
+    let matches_base = bases.iter().any(|base| {
+        let (sides, count) = corner_tiles(*base);
+        side_counts.len() == 1 && side_counts.get(&sides) == Some(&count)
+    });
+    if !matches_base {
+        issues.push(ValidationIssue::NonHexagons(non_hexagons));
+    }
+
+    for (tile_index, tile_neighbours) in neighbours.iter().enumerate() {
+        for &neighbour in tile_neighbours {
+            let neighbour = neighbour as usize;
+            let listed_back = neighbours
+                .get(neighbour)
+                .is_some_and(|back| back.contains(&(tile_index as u32)));
+            if !listed_back {
+                issues.push(ValidationIssue::AsymmetricNeighbours {
+                    tile: tile_index,
+                    neighbour,
+                });
+            }
+        }
+    }
+}
+
+/// Sides and number of the tiles on the corners of a base solid.
+fn corner_tiles(base: BaseSolid) -> (usize, usize) {
+    match base {
+        BaseSolid::Icosahedron => (5, 12),
+        BaseSolid::Octahedron => (4, 6),
+        BaseSolid::Tetrahedron => (3, 4),
+    }
+}
+
+fn finish(issues: Vec<ValidationIssue>) -> Result<(), Vec<ValidationIssue>> {
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(issues)
+    }
+}
@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+
+use web_hex::{Polyhedron, TileAddress};
+
+#[test]
+fn addresses_round_trip() {
+    for (m, n) in [(1, 0), (2, 0), (5, 0), (1, 1), (3, 3), (2, 1), (4, 3)] {
+        let polyhedron = Polyhedron::<f64>::new_truncated_goldberg_isocahedron(1.0, m, n).unwrap();
+        let mut pentagons = 0;
+        for tile_index in 0..polyhedron.tiles().len() {
+            let address = polyhedron.tile_address(tile_index).unwrap();
+            if let TileAddress::Vertex(_) = address {
+                pentagons += 1;
+                assert!(!polyhedron.tile(tile_index).is_hex());
+            }
+            assert_eq!(
+                polyhedron.tile_at(address),
+                Some(tile_index),
+                "GP({m}, {n})"
+            );
+        }
+        assert_eq!(pentagons, 12);
+    }
+}
+
+#[test]
+fn arithmetic_neighbours_match_topology() {
+    for (m, n) in [(1, 0), (3, 0), (1, 1), (2, 2), (2, 1), (5, 2)] {
+        let polyhedron = Polyhedron::<f64>::new_truncated_goldberg_isocahedron(1.0, m, n).unwrap();
+        for (tile_index, tile) in polyhedron.tiles().enumerate() {
+            let address = polyhedron.tile_address(tile_index).unwrap();
+            let arithmetic: HashSet<usize> = polyhedron
+                .address_neighbours(address)
+                .unwrap()
+                .into_iter()
+                .map(|neighbour| polyhedron.tile_at(neighbour).unwrap())
+                .collect();
+            let topological: HashSet<usize> = tile.neighbours.iter().map(|n| *n as usize).collect();
+            assert_eq!(arithmetic, topological, "GP({m}, {n}) {address:?}");
+        }
+    }
+}
+
+#[test]
+fn addresses_do_not_depend_on_radius_or_scalar() {
+    let small = Polyhedron::<f32>::new_truncated_isocahedron(0.001, 3).unwrap();
+    let large = Polyhedron::<f64>::new_truncated_isocahedron(6.371e6, 3).unwrap();
+    for tile_index in 0..small.tiles().len() {
+        assert_eq!(
+            small.tile_address(tile_index),
+            large.tile_address(tile_index)
+        );
+    }
+    assert_eq!(Polyhedron::<f32>::new().tile_address(0), None);
+    assert_eq!(
+        Polyhedron::<f32>::new().address_neighbours(TileAddress::Vertex(0)),
+        None
+    );
+    assert_eq!(small.address_neighbours(TileAddress::Vertex(12)), None);
+}
+
+#[test]
+fn only_canonical_addresses_have_tiles() {
+    let polyhedron = Polyhedron::<f64>::new_truncated_isocahedron(1.0, 1).unwrap();
+    assert_eq!(polyhedron.tile_address(polyhedron.tile_count()), None);
+    for address in [
+        TileAddress::Vertex(12),
+        TileAddress::Face {
+            face: 20,
+            i: 1,
+            j: 1,
+        },
+        TileAddress::Face {
+            face: 0,
+            i: 0,
+            j: 0,
+        },
+        TileAddress::Face {
+            face: 0,
+            i: 3,
+            j: 0,
+        },
+    ] {
+        assert_eq!(polyhedron.tile_at(address), None, "{address:?}");
+    }
+    // Each edge midpoint belongs to one of the two triangles beside it.
+    let midpoints = (0..20)
+        .flat_map(|face| [(1, 0), (1, 1), (0, 1)].map(|(i, j)| TileAddress::Face { face, i, j }))
+        .filter_map(|address| polyhedron.tile_at(address))
+        .collect::<HashSet<_>>();
+    assert_eq!(midpoints.len(), 30);
+}
@@ -0,0 +1,30 @@
+use web_hex::Polyhedron;
+
+#[test]
+fn tile_neighbours_match_the_tiles() {
+    let polyhedron = Polyhedron::<f64>::new_truncated_isocahedron_with_frequency(1.0, 4).unwrap();
+    let neighbours = polyhedron.tile_neighbours();
+    assert_eq!(neighbours.len(), polyhedron.tile_count());
+    assert_eq!(neighbours.targets().len(), 6 * 150 + 5 * 12);
+    for (tile_index, row) in neighbours.iter().enumerate() {
+        assert_eq!(row, polyhedron.tile(tile_index).neighbours);
+        assert_eq!(Some(row), neighbours.get(tile_index));
+        for neighbour in row {
+            assert!(neighbours[*neighbour as usize].contains(&(tile_index as u32)));
+        }
+    }
+    assert_eq!(neighbours.get(polyhedron.tile_count()), None);
+}
+
+#[test]
+fn faces_serialize_as_lists_of_triangles() {
+    let polyhedron = Polyhedron::<f64>::new_truncated_isocahedron(1.0, 0).unwrap();
+    let json: serde_json::Value = serde_json::to_value(&polyhedron).unwrap();
+    let faces = json["faces"].as_array().unwrap();
+    assert_eq!(faces.len(), 12);
+    for (tile_index, face) in faces.iter().enumerate() {
+        let cells: Vec<usize> = serde_json::from_value(face.clone()).unwrap();
+        assert_eq!(cells.len(), 10);
+        assert_eq!(cells[0], 10 * tile_index);
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::{HashMap, HashSet};
+
+use cgmath::InnerSpace;
+use web_hex::{BaseSolid, Polyhedron, Projection};
+
+#[test]
+fn geodesic_counts_and_winding() {
+    for (base, faces) in [
+        (BaseSolid::Icosahedron, 20),
+        (BaseSolid::Octahedron, 8),
+        (BaseSolid::Tetrahedron, 4),
+    ] {
+        for (m, n) in [(1, 0), (3, 0), (2, 2), (3, 1)] {
+            let t = m * m + m * n + n * n;
+            let geodesic =
+                Polyhedron::<f64>::new_geodesic(base, 1.0, m, n, Projection::Slerp).unwrap();
+            assert_eq!(geodesic.cells.len(), faces * t as usize, "{base:?}");
+            assert_eq!(
+                geodesic.positions.len(),
+                faces / 2 * t as usize + 2,
+                "{base:?}"
+            );
+
+            let mut edges = HashMap::new();
+            for cell in &geodesic.cells {
+                let [a, b, c] = cell.corners();
+                for (from, to) in [(a, b), (b, c), (c, a)] {
+                    *edges.entry((from.min(to), from.max(to))).or_insert(0) += 1;
+                }
+                let [a, b, c] = [a, b, c].map(|i| geodesic.positions[i].0);
+                assert!((b - a).cross(c - a).dot(a) > 0.0, "{base:?}");
+            }
+            assert!(edges.values().all(|count| *count == 2));
+        }
+    }
+}
+
+#[test]
+fn seed_vertices_become_the_odd_tiles() {
+    for (base, corners, sides) in [
+        (BaseSolid::Octahedron, 6, 4),
+        (BaseSolid::Tetrahedron, 4, 3),
+    ] {
+        let polyhedron =
+            Polyhedron::<f64>::new_truncated_geodesic(base, 2.0, 4, 0, Projection::Gnomonic)
+                .unwrap();
+        let odd: Vec<_> = polyhedron.tiles().filter(|tile| !tile.is_hex()).collect();
+        assert_eq!(odd.len(), corners);
+        assert!(odd.iter().all(|tile| tile.boundary.len() == sides));
+
+        let locator = polyhedron.tile_locator();
+        for (tile_index, tile) in polyhedron.tiles().enumerate() {
+            assert_eq!(
+                locator.locate(polyhedron.tile_center(tile_index)),
+                Some(tile_index)
+            );
+            let address = polyhedron.tile_address(tile_index).unwrap();
+            assert_eq!(polyhedron.tile_at(address), Some(tile_index));
+            let arithmetic: HashSet<usize> = polyhedron
+                .address_neighbours(address)
+                .unwrap()
+                .into_iter()
+                .map(|neighbour| polyhedron.tile_at(neighbour).unwrap())
+                .collect();
+            assert_eq!(
+                arithmetic,
+                tile.neighbours.iter().map(|n| *n as usize).collect()
+            );
+        }
+    }
+}
+
+#[test]
+fn octahedron_has_tiles_on_the_poles() {
+    let polyhedron = Polyhedron::<f64>::new_truncated_octahedron_with_frequency(1.0, 3).unwrap();
+    let locator = polyhedron.tile_locator();
+    for latitude in [90.0, -90.0] {
+        let pole = locator.locate_lat_lon(latitude, 0.0).unwrap();
+        assert_eq!(polyhedron.tile(pole).boundary.len(), 4);
+    }
+    let tetrahedron = Polyhedron::<f32>::new_truncated_tetrahedron_with_frequency(1.0, 2).unwrap();
+    assert_eq!(tetrahedron.tiles().len(), 2 * 4 + 2);
+}
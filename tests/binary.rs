@@ -0,0 +1,86 @@
+use std::io::ErrorKind;
+
+use web_hex::{BaseSolid, Polyhedron, Projection};
+
+fn to_bytes<S: cgmath::BaseFloat>(polyhedron: &Polyhedron<S>) -> Vec<u8> {
+    let mut bytes = vec![];
+    polyhedron.write_binary(&mut bytes).unwrap();
+    bytes
+}
+
+#[test]
+fn truncated_polyhedra_round_trip() {
+    let polyhedron = Polyhedron::<f64>::new_truncated_geodesic(
+        BaseSolid::Octahedron,
+        3.0,
+        2,
+        1,
+        Projection::EqualArea,
+    )
+    .unwrap();
+    let bytes = to_bytes(&polyhedron);
+    let loaded = Polyhedron::<f64>::read_binary(&mut bytes.as_slice()).unwrap();
+    assert_eq!(
+        serde_json::to_string(&loaded).unwrap(),
+        serde_json::to_string(&polyhedron).unwrap()
+    );
+    assert_eq!(to_bytes(&loaded), bytes);
+    for tile_index in 0..polyhedron.tile_count() {
+        assert_eq!(
+            loaded.tile_address(tile_index),
+            polyhedron.tile_address(tile_index)
+        );
+    }
+    assert_eq!(
+        loaded.generation_stats().hexagons,
+        polyhedron.generation_stats().hexagons
+    );
+    assert!(bytes.len() < serde_json::to_vec(&polyhedron).unwrap().len());
+}
+
+#[test]
+fn geodesic_polyhedra_round_trip() {
+    let polyhedron = Polyhedron::<f32>::new_isocahedron(1.0, 2).unwrap();
+    let loaded = Polyhedron::<f32>::read_binary(&mut to_bytes(&polyhedron).as_slice()).unwrap();
+    assert_eq!(loaded.tile_address(40), polyhedron.tile_address(40));
+    assert_eq!(
+        serde_json::to_string(&loaded.into_truncated().unwrap()).unwrap(),
+        serde_json::to_string(&polyhedron.into_truncated().unwrap()).unwrap()
+    );
+}
+
+#[test]
+fn scalars_are_converted_on_load() {
+    let polyhedron = Polyhedron::<f64>::new_truncated_isocahedron(2.0, 1).unwrap();
+    let loaded = Polyhedron::<f32>::read_binary(&mut to_bytes(&polyhedron).as_slice()).unwrap();
+    let cast = polyhedron.cast::<f32>();
+    assert_eq!(loaded.positions.len(), cast.positions.len());
+    for (loaded, cast) in loaded.positions.iter().zip(&cast.positions) {
+        assert_eq!(loaded.0, cast.0);
+    }
+    assert!(loaded.tiles().eq(cast.tiles()));
+}
+
+#[test]
+fn damaged_files_are_rejected() {
+    let bytes = to_bytes(&Polyhedron::<f32>::new_truncated_isocahedron(1.0, 1).unwrap());
+    let read = |bytes: &[u8]| Polyhedron::<f32>::read_binary(&mut &bytes[..]).unwrap_err();
+
+    let mut flipped = bytes.clone();
+    flipped[bytes.len() / 2] ^= 0x10;
+    assert_eq!(read(&flipped).to_string(), "checksum mismatch");
+
+    let mut newer = bytes.clone();
+    newer[4] = 2;
+    assert_eq!(read(&newer).to_string(), "unsupported format version 2");
+
+    assert_eq!(
+        read(b"{\"positions\": []}").kind(),
+        ErrorKind::UnexpectedEof
+    );
+    assert_eq!(
+        read(&bytes[..bytes.len() - 1]).kind(),
+        ErrorKind::UnexpectedEof
+    );
+    assert_eq!(read(&[b'X'; 64]).to_string(), "not a binary polyhedron");
+}
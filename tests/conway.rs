@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+
+use cgmath::InnerSpace;
+use web_hex::{PolyMesh, Polyhedron};
+
+fn counts(mesh: &PolyMesh<f64>) -> (usize, usize, usize) {
+    let edges: usize = mesh.faces.iter().map(|face| face.len()).sum();
+    (mesh.positions.len(), edges / 2, mesh.faces.len())
+}
+
+fn icosahedron() -> PolyMesh<f64> {
+    PolyMesh::from(&Polyhedron::<f64>::new_isocahedron(1.0, 0).unwrap())
+}
+
+#[test]
+fn operators_change_counts_as_expected() {
+    for seed in [
+        icosahedron(),
+        icosahedron().dual().unwrap(),
+        icosahedron().ambo().unwrap(),
+    ] {
+        let (v, e, f) = counts(&seed);
+        assert_eq!(v + f, e + 2);
+        assert_eq!(counts(&seed.dual().unwrap()), (f, e, v));
+        assert_eq!(counts(&seed.kis().unwrap()), (v + f, 3 * e, 2 * e));
+        assert_eq!(counts(&seed.ambo().unwrap()), (e, 2 * e, v + f));
+        assert_eq!(counts(&seed.truncate().unwrap()), (2 * e, 3 * e, v + f));
+        assert_eq!(counts(&seed.chamfer().unwrap()), (v + 2 * e, 4 * e, f + e));
+    }
+}
+
+#[test]
+fn operators_stay_on_the_sphere() {
+    let mesh = icosahedron()
+        .dual()
+        .unwrap()
+        .chamfer()
+        .unwrap()
+        .truncate()
+        .unwrap()
+        .kis()
+        .unwrap()
+        .ambo()
+        .unwrap();
+    for position in &mesh.positions {
+        assert!((position.magnitude() - 1.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn dual_of_geodesic_matches_truncation() {
+    let geodesic = Polyhedron::<f64>::new_isocahedron(2.0, 2).unwrap();
+    let truncated = Polyhedron::<f64>::new_truncated_isocahedron(2.0, 2).unwrap();
+    let dual = PolyMesh::from(&geodesic)
+        .dual()
+        .unwrap()
+        .to_polyhedron()
+        .unwrap();
+    assert_eq!(dual.tiles().len(), truncated.tiles().len());
+    for (expected, tile) in truncated.tiles().zip(dual.tiles()) {
+        let expected: HashSet<_> = expected.neighbours.iter().collect();
+        assert_eq!(tile.neighbours.iter().collect::<HashSet<_>>(), expected);
+    }
+}
+
+#[test]
+fn chamfered_dodecahedron_tiles() {
+    let chamfered = PolyMesh::from(&Polyhedron::<f64>::new_truncated_isocahedron(1.0, 0).unwrap())
+        .chamfer()
+        .unwrap()
+        .to_polyhedron()
+        .unwrap();
+    let tiles: Vec<_> = chamfered.tiles().collect();
+    assert_eq!(tiles.iter().filter(|tile| !tile.is_hex()).count(), 12);
+    assert_eq!(tiles.iter().filter(|tile| tile.is_hex()).count(), 30);
+    for (tile_index, tile) in tiles.iter().enumerate() {
+        for neighbour in tile.neighbours {
+            assert!(tiles[*neighbour as usize]
+                .neighbours
+                .contains(&(tile_index as u32)));
+        }
+    }
+    // Faces still wind outwards.
+    for cell in &chamfered.cells {
+        let [a, b, c] = cell.corners().map(|i| chamfered.positions[i].0);
+        assert!((b - a).cross(c - a).dot(a) > 0.0);
+    }
+    assert_eq!(chamfered.to_tiles().tiles.len(), 42);
+}
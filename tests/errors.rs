@@ -0,0 +1,84 @@
+use web_hex::{ArraySerializedVector, PolyMesh, Polyhedron, PolyhedronError};
+
+#[test]
+fn rejects_unusable_subdivisions() {
+    assert_eq!(
+        Polyhedron::<f64>::new_isocahedron(1.0, 40).unwrap_err(),
+        PolyhedronError::InvalidDetail(40)
+    );
+    assert_eq!(
+        Polyhedron::<f64>::new_goldberg_isocahedron(1.0, 0, 0).unwrap_err(),
+        PolyhedronError::InvalidFrequency { m: 0, n: 0 }
+    );
+    assert_eq!(
+        Polyhedron::<f64>::new_isocahedron_with_frequency(1.0, 100_000).unwrap_err(),
+        PolyhedronError::InvalidFrequency { m: 100_000, n: 0 }
+    );
+    // Detail 12 would need more than `u32` indices once truncated.
+    assert_eq!(
+        Polyhedron::<f64>::new_isocahedron(1.0, 12).unwrap_err(),
+        PolyhedronError::InvalidFrequency { m: 4096, n: 0 }
+    );
+}
+
+#[test]
+fn truncating_an_open_surface_fails() {
+    let mut polyhedron = Polyhedron::<f64>::new_isocahedron(1.0, 0).unwrap();
+    let removed = polyhedron.cells.pop().unwrap();
+    let error = polyhedron.into_truncated().unwrap_err();
+    let PolyhedronError::NonManifoldEdge { a, b } = error else {
+        panic!("expected a non-manifold edge, got {error:?}");
+    };
+    let corners = removed.corners();
+    assert!(corners.contains(&a) && corners.contains(&b));
+}
+
+#[test]
+fn truncating_with_a_flipped_triangle_fails() {
+    let mut polyhedron = Polyhedron::<f64>::new_isocahedron(1.0, 0).unwrap();
+    let cell = &mut polyhedron.cells[4];
+    std::mem::swap(&mut cell.b, &mut cell.c);
+    let corners = cell.corners();
+    let error = polyhedron.into_truncated().unwrap_err();
+    let PolyhedronError::NonManifoldEdge { a, b } = error else {
+        panic!("expected a non-manifold edge, got {error:?}");
+    };
+    assert!(corners.contains(&a) && corners.contains(&b));
+}
+
+#[test]
+fn truncating_with_an_unused_vertex_fails() {
+    let mut polyhedron = Polyhedron::<f64>::new_isocahedron(1.0, 0).unwrap();
+    let extra = polyhedron.positions[0].0;
+    polyhedron.positions.push(ArraySerializedVector(extra));
+    assert_eq!(
+        polyhedron.into_truncated().unwrap_err(),
+        PolyhedronError::IsolatedVertex(12)
+    );
+}
+
+#[test]
+fn operators_reject_broken_meshes() {
+    let mut open = PolyMesh::from(&Polyhedron::<f64>::new_isocahedron(1.0, 0).unwrap());
+    open.faces.pop();
+    assert!(matches!(
+        open.dual(),
+        Err(PolyhedronError::NonManifoldEdge { .. })
+    ));
+    assert!(open.to_polyhedron().is_err());
+
+    let mut out_of_range = PolyMesh::from(&Polyhedron::<f64>::new_isocahedron(1.0, 0).unwrap());
+    out_of_range.faces[0][0] = 99;
+    let error = PolyhedronError::VertexOutOfRange {
+        face: 0,
+        vertex: 99,
+    };
+    assert_eq!(out_of_range.truncate().unwrap_err(), error);
+    assert_eq!(out_of_range.kis().unwrap_err(), error);
+}
+
+#[test]
+fn errors_describe_themselves() {
+    let message = PolyhedronError::NonManifoldEdge { a: 3, b: 7 }.to_string();
+    assert!(message.contains("3-7"), "{message}");
+}
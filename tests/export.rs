@@ -0,0 +1,96 @@
+use web_hex::Polyhedron;
+
+fn tiles() -> Polyhedron<f64> {
+    let mut polyhedron = Polyhedron::<f64>::new_truncated_isocahedron(2.0, 1).unwrap();
+    polyhedron.assign_seeded_face_colors(3);
+    polyhedron
+}
+
+#[test]
+fn obj_has_a_group_per_tile() {
+    let mut polyhedron = tiles();
+    let mut obj = vec![];
+    polyhedron.write_obj(&mut obj).unwrap();
+    let obj = String::from_utf8(obj).unwrap();
+    let count = |prefix: &str| obj.lines().filter(|line| line.starts_with(prefix)).count();
+    assert_eq!(count("v "), polyhedron.positions.len());
+    assert_eq!(count("vn "), 0);
+    assert_eq!(count("g tile_"), polyhedron.tiles().len());
+    assert_eq!(count("f "), polyhedron.cells.len());
+
+    polyhedron.compute_face_normals();
+    let mut obj = vec![];
+    polyhedron.write_obj(&mut obj).unwrap();
+    let obj = String::from_utf8(obj).unwrap();
+    assert_eq!(
+        obj.lines().filter(|line| line.starts_with("vn ")).count(),
+        polyhedron.positions.len()
+    );
+    assert!(obj
+        .lines()
+        .any(|line| line.starts_with("f ") && line.contains("//")));
+}
+
+#[test]
+fn ply_lists_colored_vertices_and_faces() {
+    let polyhedron = tiles();
+    let mut ply = vec![];
+    polyhedron.write_ply(&mut ply).unwrap();
+    let ply = String::from_utf8(ply).unwrap();
+    let (header, body) = ply.split_once("end_header\n").unwrap();
+    assert!(header.contains(&format!("element vertex {}", polyhedron.positions.len())));
+    assert!(header.contains(&format!("element face {}", polyhedron.cells.len())));
+    assert!(header.contains("property uchar red"));
+    let lines: Vec<&str> = body.lines().collect();
+    assert_eq!(
+        lines.len(),
+        polyhedron.positions.len() + polyhedron.cells.len()
+    );
+    assert_eq!(lines[0].split(' ').count(), 6);
+}
+
+#[test]
+fn glb_is_well_formed() {
+    let mut polyhedron = tiles();
+    polyhedron.compute_face_normals();
+    let mut glb = vec![];
+    polyhedron.write_glb(&mut glb).unwrap();
+
+    let word = |offset: usize| u32::from_le_bytes(glb[offset..offset + 4].try_into().unwrap());
+    assert_eq!(&glb[0..4], b"glTF");
+    assert_eq!(word(4), 2);
+    assert_eq!(word(8) as usize, glb.len());
+    let json_length = word(12) as usize;
+    assert_eq!(&glb[16..20], b"JSON");
+    let document: serde_json::Value = serde_json::from_slice(&glb[20..20 + json_length]).unwrap();
+    let bin_header = 20 + json_length;
+    assert_eq!(&glb[bin_header + 4..bin_header + 8], b"BIN\0");
+    assert_eq!(
+        document["buffers"][0]["byteLength"].as_u64().unwrap() as usize,
+        polyhedron.positions.len() * 3 * 12 + polyhedron.cells.len() * 12
+    );
+
+    let attributes = &document["meshes"][0]["primitives"][0]["attributes"];
+    for name in ["POSITION", "NORMAL", "COLOR_0"] {
+        let accessor = &document["accessors"][attributes[name].as_u64().unwrap() as usize];
+        assert_eq!(
+            accessor["count"].as_u64().unwrap() as usize,
+            polyhedron.positions.len()
+        );
+    }
+    let indices = document["meshes"][0]["primitives"][0]["indices"]
+        .as_u64()
+        .unwrap() as usize;
+    assert_eq!(
+        document["accessors"][indices]["count"].as_u64().unwrap() as usize,
+        polyhedron.cells.len() * 3
+    );
+}
+
+#[test]
+fn glb_needs_a_mesh() {
+    let mut glb = vec![];
+    let error = Polyhedron::<f64>::new().write_glb(&mut glb).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+    assert!(glb.is_empty());
+}
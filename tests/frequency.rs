@@ -0,0 +1,47 @@
+use web_hex::Polyhedron;
+
+/// Vertices of an icosahedral GP(m, n) sphere: 10 T + 2 with T = m² + mn + n².
+fn expected_count(m: u32, n: u32) -> usize {
+    10 * (m * m + m * n + n * n) as usize + 2
+}
+
+#[test]
+fn goldberg_classes_have_ten_t_plus_two_tiles() {
+    // Class I, class II and chiral class III.
+    for (m, n) in [
+        (1, 0),
+        (3, 0),
+        (1, 1),
+        (2, 2),
+        (2, 1),
+        (1, 2),
+        (3, 1),
+        (4, 3),
+    ] {
+        let geodesic = Polyhedron::<f64>::new_goldberg_isocahedron(1.0, m, n).unwrap();
+        assert_eq!(
+            geodesic.positions.len(),
+            expected_count(m, n),
+            "GP({m}, {n})"
+        );
+        assert_eq!(geodesic.cells.len(), 2 * expected_count(m, n) - 4);
+
+        let truncated = Polyhedron::<f64>::new_truncated_goldberg_isocahedron(1.0, m, n).unwrap();
+        assert_eq!(truncated.tile_count(), expected_count(m, n), "GP({m}, {n})");
+        let pentagons = truncated.tiles().filter(|tile| !tile.is_hex()).count();
+        assert_eq!(pentagons, 12);
+    }
+}
+
+#[test]
+fn any_frequency_is_allowed() {
+    // The sizes the Unity project uses, none a power of two.
+    for (frequency, tiles) in [(3, 92), (6, 362), (9, 812), (10, 1002), (20, 4002)] {
+        let geodesic = Polyhedron::<f64>::new_isocahedron_with_frequency(1.0, frequency).unwrap();
+        assert_eq!(geodesic.positions.len(), tiles);
+        let truncated =
+            Polyhedron::<f64>::new_truncated_isocahedron_with_frequency(1.0, frequency).unwrap();
+        assert_eq!(truncated.tile_count(), tiles);
+        assert_eq!(tiles, expected_count(frequency, 0));
+    }
+}
@@ -0,0 +1,98 @@
+//! Compares small spheres against snapshots in `tests/golden`. After a
+//! deliberate change to generation, rewrite them with
+//! `UPDATE_GOLDEN=1 cargo test --test golden` and review the diff.
+
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use web_hex::{BaseSolid, Polyhedron, Projection};
+
+/// Positions to six decimals, triangles and, once truncated, tiles, one per
+/// line.
+fn snapshot(polyhedron: &Polyhedron<f64>) -> String {
+    let round = |value: f64| if value.abs() < 5e-7 { 0.0 } else { value };
+    let mut text = String::new();
+    writeln!(text, "positions {}", polyhedron.positions.len()).unwrap();
+    for position in &polyhedron.positions {
+        let [x, y, z] = [position.0.x, position.0.y, position.0.z].map(round);
+        writeln!(text, "{x:.6} {y:.6} {z:.6}").unwrap();
+    }
+    writeln!(text, "cells {}", polyhedron.cells.len()).unwrap();
+    for cell in &polyhedron.cells {
+        writeln!(text, "{} {} {}", cell.a, cell.b, cell.c).unwrap();
+    }
+    if polyhedron.tile_count() > 0 {
+        writeln!(text, "tiles {}", polyhedron.tile_count()).unwrap();
+        for tile in polyhedron.tiles() {
+            writeln!(
+                text,
+                "{} {:?} {:?}",
+                tile.center, tile.boundary, tile.neighbours
+            )
+            .unwrap();
+        }
+    }
+    text
+}
+
+fn check(name: &str, polyhedron: Polyhedron<f64>) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(format!("{name}.txt"));
+    let actual = snapshot(&polyhedron);
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+    let expected = fs::read_to_string(&path).unwrap_or_default();
+    if let Some((line, (expected, actual))) = expected
+        .lines()
+        .zip(actual.lines())
+        .enumerate()
+        .find(|(_, (expected, actual))| expected != actual)
+    {
+        panic!(
+            "{name} differs at line {}: expected {expected}, got {actual}",
+            line + 1
+        );
+    }
+    assert_eq!(
+        expected.lines().count(),
+        actual.lines().count(),
+        "{name} has a different number of lines"
+    );
+}
+
+#[test]
+fn subdivided_icosahedra_match_their_snapshots() {
+    check(
+        "icosahedron_0",
+        Polyhedron::new_isocahedron(1.0, 0).unwrap(),
+    );
+    check(
+        "icosahedron_1",
+        Polyhedron::new_isocahedron(1.0, 1).unwrap(),
+    );
+    check(
+        "goldberg_2_1",
+        Polyhedron::new_goldberg_isocahedron(1.0, 2, 1).unwrap(),
+    );
+}
+
+#[test]
+fn truncated_spheres_match_their_snapshots() {
+    check(
+        "truncated_icosahedron_0",
+        Polyhedron::new_truncated_isocahedron(1.0, 0).unwrap(),
+    );
+    check(
+        "truncated_icosahedron_1",
+        Polyhedron::new_truncated_isocahedron(1.0, 1).unwrap(),
+    );
+    check(
+        "truncated_octahedron_2",
+        Polyhedron::new_truncated_geodesic(BaseSolid::Octahedron, 1.0, 2, 0, Projection::EqualArea)
+            .unwrap(),
+    );
+}
@@ -0,0 +1,75 @@
+use std::f64::consts::PI;
+
+use web_hex::Polyhedron;
+
+#[test]
+fn rings_grow_like_a_hexagonal_grid() {
+    let polyhedron = Polyhedron::<f64>::new_truncated_isocahedron_with_frequency(1.0, 12).unwrap();
+    let pentagons: Vec<usize> = (0..polyhedron.tiles().len())
+        .filter(|tile_index| !polyhedron.tile(*tile_index).is_hex())
+        .collect();
+    let pentagon = pentagons[0];
+    for k in 1..4 {
+        assert_eq!(polyhedron.tile_ring(pentagon, k).count(), 5 * k);
+    }
+
+    // The tile farthest from every pentagon sits at the middle of a base
+    // face, where rings are regular hexagons.
+    let mut nearest_pentagon = vec![usize::MAX; polyhedron.tiles().len()];
+    for pentagon in &pentagons {
+        for (tile_index, hops) in polyhedron.hops_from(*pentagon, usize::MAX) {
+            nearest_pentagon[tile_index] = nearest_pentagon[tile_index].min(hops);
+        }
+    }
+    let hexagon = (0..nearest_pentagon.len())
+        .max_by_key(|tile_index| nearest_pentagon[*tile_index])
+        .unwrap();
+    for k in 0..3 {
+        let ring = if k == 0 { 1 } else { 6 * k };
+        assert_eq!(polyhedron.tile_ring(hexagon, k).count(), ring);
+        assert_eq!(
+            polyhedron.tiles_within(hexagon, k).count(),
+            1 + 3 * k * (k + 1)
+        );
+    }
+}
+
+#[test]
+fn hop_distance_is_a_metric() {
+    let polyhedron = Polyhedron::<f64>::new_truncated_goldberg_isocahedron(1.0, 3, 1).unwrap();
+    let tiles = [0, 5, 17, 42, 100];
+    for a in tiles {
+        assert_eq!(polyhedron.hop_distance(a, a), Some(0));
+        for neighbour in polyhedron.tile(a).neighbours {
+            assert_eq!(polyhedron.hop_distance(a, *neighbour as usize), Some(1));
+        }
+        for b in tiles {
+            assert_eq!(polyhedron.hop_distance(a, b), polyhedron.hop_distance(b, a));
+            for c in tiles {
+                let hops = |from, to| polyhedron.hop_distance(from, to).unwrap();
+                assert!(hops(a, c) <= hops(a, b) + hops(b, c));
+            }
+        }
+    }
+
+    let tile_count = polyhedron.tiles().len();
+    assert_eq!(polyhedron.hop_distance(0, tile_count), None);
+    assert_eq!(polyhedron.hop_distance(tile_count, 0), None);
+    assert_eq!(polyhedron.hops_from(tile_count, 3).count(), 0);
+}
+
+#[test]
+fn geodesic_distance_between_centers() {
+    let polyhedron = Polyhedron::<f64>::new_truncated_isocahedron(3.0, 0).unwrap();
+    let tile_count = polyhedron.tiles().len();
+    for a in 0..tile_count {
+        assert!(polyhedron.tile_distance(a, a).unwrap().abs() < 1e-9);
+        let farthest = (0..tile_count)
+            .map(|b| polyhedron.tile_distance(a, b).unwrap())
+            .fold(0.0, f64::max);
+        // Every dodecahedron face has an opposite face.
+        assert!((farthest - 3.0 * PI).abs() < 1e-6);
+    }
+    assert_eq!(polyhedron.tile_distance(0, tile_count), None);
+    assert_eq!(polyhedron.tile_distance(tile_count, 0), None);
+}
@@ -0,0 +1,82 @@
+use web_hex::{BaseSolid, Polyhedron, Projection, TileHierarchy};
+
+#[test]
+fn every_tile_maps_between_detail_levels() {
+    for detail in 0..4 {
+        let coarse = Polyhedron::<f64>::new_truncated_isocahedron(2.0, detail).unwrap();
+        let fine = Polyhedron::<f64>::new_truncated_isocahedron(2.0, detail + 1).unwrap();
+        let hierarchy = TileHierarchy::new(&coarse, &fine);
+        let fine_locator = fine.tile_locator();
+
+        let mut child_count = 0;
+        for tile_index in 0..coarse.tiles().len() {
+            let children = hierarchy.children(tile_index);
+            child_count += children.len();
+            for child in children {
+                assert_eq!(hierarchy.parent(*child as usize), tile_index);
+            }
+            // The fine tile at the coarse tile's center is always its child.
+            let center_child = fine_locator.locate(coarse.tile_center(tile_index)).unwrap();
+            assert!(children.contains(&(center_child as u32)));
+        }
+        assert_eq!(child_count, fine.tiles().len());
+    }
+}
+
+#[test]
+fn children_are_shared_out_evenly() {
+    for base in BaseSolid::ALL {
+        for (m, n) in [(1, 0), (2, 0), (1, 1), (2, 1)] {
+            let sphere = |m, n| {
+                Polyhedron::<f64>::new_geodesic(base, 1.0, m, n, Projection::Slerp)
+                    .unwrap()
+                    .into_truncated()
+                    .unwrap()
+            };
+            let (coarse, fine) = (sphere(m, n), sphere(2 * m, 2 * n));
+            let hierarchy = TileHierarchy::new(&coarse, &fine);
+            let fine_locator = fine.tile_locator();
+            for (tile_index, tile) in coarse.tiles().enumerate() {
+                // Its own center plus half of its edges.
+                let children = hierarchy.children(tile_index).len();
+                let sides = tile.neighbours.len();
+                assert!(
+                    children == 1 + sides / 2 || children == 1 + sides.div_ceil(2),
+                    "{base:?} GP({m}, {n}) tile {tile_index}: {children} children"
+                );
+                if tile.is_hex() {
+                    assert_eq!(children, 4);
+                }
+                let center_child = fine_locator.locate(coarse.tile_center(tile_index)).unwrap();
+                assert_eq!(hierarchy.parent(center_child), tile_index);
+            }
+
+            // Children on a coarse boundary go to one of the tiles either
+            // side of it.
+            let coarse_locator = coarse.tile_locator();
+            for tile_index in 0..fine.tiles().len() {
+                let parent = hierarchy.parent(tile_index);
+                let located = coarse_locator.locate(fine.tile_center(tile_index)).unwrap();
+                assert!(
+                    parent == located || coarse.tile(located).neighbours.contains(&(parent as u32))
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn values_move_up_and_down_the_hierarchy() {
+    let coarse = Polyhedron::<f32>::new_truncated_isocahedron(1.0, 1).unwrap();
+    let fine = Polyhedron::<f32>::new_truncated_isocahedron(1.0, 2).unwrap();
+    let hierarchy = TileHierarchy::new(&coarse, &fine);
+
+    let counts = hierarchy.aggregate(&vec![1; fine.tiles().len()], |children| children.len());
+    assert_eq!(counts.iter().sum::<usize>(), fine.tiles().len());
+
+    let coarse_ids: Vec<usize> = (0..coarse.tiles().len()).collect();
+    let refined = hierarchy.refine(&coarse_ids);
+    for (tile_index, parent) in refined.iter().enumerate() {
+        assert_eq!(*parent, hierarchy.parent(tile_index));
+    }
+}
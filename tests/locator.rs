@@ -0,0 +1,63 @@
+use cgmath::{InnerSpace, Vector3};
+use rand::prelude::*;
+use web_hex::{lat_lon_to_direction, Polyhedron};
+
+/// Whether `direction` lies inside the tile's spherical polygon.
+fn contains(polyhedron: &Polyhedron<f64>, tile_index: usize, direction: Vector3<f64>) -> bool {
+    let boundary = &polyhedron.tile(tile_index).boundary;
+    (0..boundary.len()).all(|k| {
+        let [a, b] = [boundary[k], boundary[(k + 1) % boundary.len()]]
+            .map(|corner| polyhedron.positions[corner as usize].0);
+        a.cross(b).dot(direction) >= -1e-12
+    })
+}
+
+#[test]
+fn tile_centers_locate_their_own_tile() {
+    for frequency in [1, 3, 10] {
+        let polyhedron =
+            Polyhedron::<f64>::new_truncated_isocahedron_with_frequency(5.0, frequency).unwrap();
+        let locator = polyhedron.tile_locator();
+        for tile_index in 0..polyhedron.tiles().len() {
+            let center = polyhedron.tile_center(tile_index);
+            assert_eq!(locator.locate(center), Some(tile_index));
+        }
+    }
+}
+
+#[test]
+fn random_directions_land_inside_the_located_tile() {
+    let mut rng = StdRng::seed_from_u64(7);
+    for (m, n) in [(1, 0), (4, 0), (3, 2), (16, 0)] {
+        let polyhedron = Polyhedron::<f64>::new_truncated_goldberg_isocahedron(1.0, m, n).unwrap();
+        let locator = polyhedron.tile_locator();
+        for _ in 0..2000 {
+            let direction = Vector3::new(
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+            );
+            let tile_index = locator.locate(direction).unwrap();
+            assert!(contains(&polyhedron, tile_index, direction), "GP({m}, {n})");
+        }
+    }
+}
+
+#[test]
+fn lat_lon_lookup() {
+    let polyhedron = Polyhedron::<f64>::new_truncated_isocahedron(1.0, 2).unwrap();
+    let locator = polyhedron.tile_locator();
+    assert!((lat_lon_to_direction(90.0, 0.0) - Vector3::unit_y()).magnitude() < 1e-12);
+    assert!((lat_lon_to_direction(0.0, 90.0) + Vector3::unit_z()).magnitude() < 1e-12);
+    assert_eq!(
+        locator.locate_lat_lon(12.5, -40.0),
+        locator.locate(lat_lon_to_direction(12.5, -40.0))
+    );
+    assert_eq!(locator.locate(Vector3::new(0.0, 0.0, 0.0)), None);
+    assert_eq!(
+        Polyhedron::<f64>::new()
+            .tile_locator()
+            .locate(Vector3::unit_x()),
+        None
+    );
+}
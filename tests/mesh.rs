@@ -0,0 +1,50 @@
+use bevy::render::mesh::{Mesh, VertexAttributeValues};
+use web_hex::Polyhedron;
+
+#[test]
+fn tile_meshes_fan_around_their_centers() {
+    let mut polyhedron =
+        Polyhedron::<f32>::new_truncated_isocahedron_with_frequency(5.0, 3).unwrap();
+    polyhedron.assign_seeded_face_colors(42);
+    let meshes = polyhedron.tile_meshes();
+    assert_eq!(meshes.len(), 92);
+
+    for (tile, mesh) in polyhedron.tiles().zip(&meshes) {
+        let corners = tile.boundary.len();
+        assert_eq!(mesh.count_vertices(), corners + 1);
+        assert_eq!(mesh.indices().unwrap().len(), 3 * corners);
+        for attribute in [
+            Mesh::ATTRIBUTE_NORMAL,
+            Mesh::ATTRIBUTE_COLOR,
+            Mesh::ATTRIBUTE_UV_0,
+        ] {
+            assert!(mesh.attribute(attribute).is_some());
+        }
+        let Some(VertexAttributeValues::Float32x4(colors)) = mesh.attribute(Mesh::ATTRIBUTE_COLOR)
+        else {
+            panic!("colours are RGBA floats");
+        };
+        assert!(colors.iter().all(|color| *color == colors[0]));
+    }
+
+    let combined = polyhedron.to_mesh();
+    let vertices: usize = meshes.iter().map(|mesh| mesh.count_vertices()).sum();
+    let indices: usize = meshes
+        .iter()
+        .map(|mesh| mesh.indices().unwrap().len())
+        .sum();
+    assert_eq!(combined.count_vertices(), vertices);
+    assert_eq!(combined.indices().unwrap().len(), indices);
+}
+
+#[test]
+fn geodesic_mesh_keeps_shared_vertices() {
+    let polyhedron = Polyhedron::<f64>::new_isocahedron(1.0, 2).unwrap();
+    let mesh = polyhedron.to_mesh();
+    assert_eq!(mesh.count_vertices(), polyhedron.positions.len());
+    assert_eq!(mesh.indices().unwrap().len(), polyhedron.cells.len() * 3);
+    let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute(Mesh::ATTRIBUTE_UV_0) else {
+        panic!("uvs are float pairs");
+    };
+    assert!(uvs.iter().flatten().all(|uv| (0.0..=1.0).contains(uv)));
+}
@@ -0,0 +1,42 @@
+use std::f64::consts::PI;
+
+use web_hex::Polyhedron;
+
+#[test]
+fn tile_areas_cover_the_sphere() {
+    for frequency in [1, 2, 5] {
+        let polyhedron =
+            Polyhedron::<f64>::new_truncated_isocahedron_with_frequency(2.0, frequency).unwrap();
+        let report = polyhedron.metrics_report();
+        let total: f64 = report.tiles.iter().map(|tile| tile.area).sum();
+        assert!(
+            (total - 4.0 * PI * 4.0).abs() < 1e-9,
+            "{frequency}: {total}"
+        );
+        assert_eq!(report.tiles.len(), polyhedron.tiles().len());
+        assert!(report.area.min <= report.area.mean + 1e-12);
+        assert!(report.area.mean <= report.area.max + 1e-12);
+    }
+}
+
+#[test]
+fn dodecahedron_tiles_are_regular() {
+    let report = Polyhedron::<f64>::new_truncated_isocahedron(1.0, 0)
+        .unwrap()
+        .metrics_report();
+    assert!(report.irregularity.max < 1e-9);
+    assert!(report.area.std_dev < 1e-9);
+    assert!((report.area.mean - PI / 3.0).abs() < 1e-9);
+    // Twelve pentagons share thirty edges between them.
+    let perimeter: f64 = report.tiles.iter().map(|tile| tile.perimeter).sum();
+    assert!((perimeter / 2.0 / 30.0 - report.perimeter.mean / 5.0).abs() < 1e-9);
+}
+
+#[test]
+fn relaxation_lowers_irregularity() {
+    let mut geodesic = Polyhedron::<f64>::new_isocahedron_with_frequency(1.0, 6).unwrap();
+    let before = geodesic.clone().into_truncated().unwrap().metrics_report();
+    geodesic.relax(30, 1e-9).unwrap();
+    let after = geodesic.into_truncated().unwrap().metrics_report();
+    assert!(after.irregularity.mean < before.irregularity.mean);
+}
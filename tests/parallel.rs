@@ -0,0 +1,81 @@
+use web_hex::{BaseSolid, Polyhedron, PolyhedronError, Projection};
+
+/// Everything observable about a polyhedron, to compare two exactly.
+fn fingerprint(polyhedron: &Polyhedron<f64>) -> String {
+    let addresses: Vec<_> = (0..polyhedron.tiles().len().max(polyhedron.positions.len()))
+        .map(|index| polyhedron.tile_address(index))
+        .collect();
+    let stats = polyhedron.generation_stats();
+    format!(
+        "{}\n{:?}\n{:?}\n{:?}",
+        serde_json::to_string(polyhedron).unwrap(),
+        polyhedron.tiles().collect::<Vec<_>>(),
+        addresses,
+        (
+            stats.hexagons,
+            stats.pentagons,
+            stats.other_tiles,
+            stats.vertices,
+            stats.triangles,
+            stats.shared_corners
+        ),
+    )
+}
+
+#[test]
+fn parallel_generation_matches_serial() {
+    let cases = [
+        (BaseSolid::Icosahedron, 4, 0, Projection::Gnomonic),
+        (BaseSolid::Icosahedron, 2, 1, Projection::EqualArea),
+        (BaseSolid::Icosahedron, 3, 3, Projection::Slerp),
+        (BaseSolid::Octahedron, 5, 2, Projection::Gnomonic),
+        (BaseSolid::Tetrahedron, 3, 0, Projection::Slerp),
+    ];
+    for (base, m, n, projection) in cases {
+        let serial = Polyhedron::new_geodesic(base, 3.0, m, n, projection).unwrap();
+        let truncated_serial =
+            Polyhedron::new_truncated_geodesic(base, 3.0, m, n, projection).unwrap();
+        for threads in [0, 1, 2, 3, 7, 64] {
+            let parallel =
+                Polyhedron::new_geodesic_parallel(base, 3.0, m, n, projection, threads).unwrap();
+            assert_eq!(
+                fingerprint(&parallel),
+                fingerprint(&serial),
+                "{base:?} GP({m}, {n})"
+            );
+
+            let truncated = parallel.into_truncated_parallel(threads).unwrap();
+            assert_eq!(
+                fingerprint(&truncated),
+                fingerprint(&truncated_serial),
+                "{base:?} GP({m}, {n}) truncated on {threads} threads"
+            );
+        }
+    }
+}
+
+#[test]
+fn parallel_generation_reports_the_same_errors() {
+    assert_eq!(
+        Polyhedron::<f64>::new_truncated_geodesic_parallel(
+            BaseSolid::Icosahedron,
+            1.0,
+            0,
+            0,
+            Projection::Gnomonic,
+            4
+        )
+        .unwrap_err(),
+        PolyhedronError::InvalidFrequency { m: 0, n: 0 }
+    );
+
+    let open = || {
+        let mut polyhedron = Polyhedron::<f64>::new_isocahedron(1.0, 1).unwrap();
+        polyhedron.cells.pop();
+        polyhedron
+    };
+    assert_eq!(
+        open().into_truncated_parallel(4).unwrap_err(),
+        open().into_truncated().unwrap_err()
+    );
+}
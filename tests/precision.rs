@@ -0,0 +1,51 @@
+use cgmath::InnerSpace;
+use web_hex::Polyhedron;
+
+const EARTH_RADIUS: f64 = 6.371e6;
+
+/// Largest distance of a vertex from the sphere, relative to the radius.
+fn worst_drift(positions: impl Iterator<Item = f64>) -> f64 {
+    positions
+        .map(|distance| (distance / EARTH_RADIUS - 1.0).abs())
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn f64_holds_planet_scale_spheres_closer() {
+    let wide = Polyhedron::<f64>::new_isocahedron(EARTH_RADIUS, 5).unwrap();
+    let narrow = Polyhedron::<f32>::new_isocahedron(EARTH_RADIUS as f32, 5).unwrap();
+    let wide_drift = worst_drift(wide.positions.iter().map(|p| p.0.magnitude()));
+    let narrow_drift = worst_drift(
+        narrow
+            .positions
+            .iter()
+            .map(|p| p.0.cast::<f64>().unwrap().magnitude()),
+    );
+    assert!(wide_drift < 1e-12, "{wide_drift}");
+    assert!(narrow_drift > 1e3 * wide_drift, "{narrow_drift}");
+}
+
+#[test]
+fn cast_keeps_everything_but_the_precision() {
+    let wide = Polyhedron::<f64>::new_truncated_isocahedron(EARTH_RADIUS, 3).unwrap();
+    let narrow = wide.cast::<f32>();
+    for (cast, original) in narrow.positions.iter().zip(&wide.positions) {
+        assert_eq!(cast.0, original.0.cast::<f32>().unwrap());
+    }
+    assert_eq!(narrow.export_cells(), wide.export_cells());
+    assert!(narrow.tiles().eq(wide.tiles()));
+    assert_eq!(narrow.radius(), EARTH_RADIUS as f32);
+    for tile_index in 0..wide.tile_count() {
+        assert_eq!(
+            narrow.tile_address(tile_index),
+            wide.tile_address(tile_index)
+        );
+    }
+
+    // Widening loses nothing, so narrowing again gives the same polyhedron.
+    let again = narrow.cast::<f64>().cast::<f32>();
+    assert_eq!(
+        serde_json::to_string(&again).unwrap(),
+        serde_json::to_string(&narrow).unwrap()
+    );
+}
@@ -0,0 +1,40 @@
+use cgmath::InnerSpace;
+use web_hex::{Polyhedron, Projection};
+
+fn area_ratio(projection: Projection) -> f64 {
+    Polyhedron::<f64>::new_truncated_projected_isocahedron(1.0, 8, 0, projection)
+        .unwrap()
+        .tile_area_ratio()
+}
+
+#[test]
+fn equal_area_projection_evens_out_tile_areas() {
+    let gnomonic = area_ratio(Projection::Gnomonic);
+    let slerp = area_ratio(Projection::Slerp);
+    let equal_area = area_ratio(Projection::EqualArea);
+
+    assert!(gnomonic > 1.5, "gnomonic ratio {gnomonic}");
+    assert!(slerp < gnomonic, "slerp ratio {slerp}");
+    assert!(equal_area < slerp, "equal-area ratio {equal_area}");
+    assert!(equal_area < 1.05, "equal-area ratio {equal_area}");
+}
+
+#[test]
+fn every_projection_keeps_vertices_on_the_sphere() {
+    for projection in [
+        Projection::Gnomonic,
+        Projection::Slerp,
+        Projection::EqualArea,
+    ] {
+        for (m, n) in [(6, 0), (2, 2), (4, 1)] {
+            let polyhedron: Polyhedron<f64> =
+                Polyhedron::new_projected_isocahedron(3.0, m, n, projection).unwrap();
+            for position in &polyhedron.positions {
+                assert!(
+                    (position.0.magnitude() - 3.0).abs() < 1e-9,
+                    "{projection:?}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use cgmath::{BaseFloat, InnerSpace};
+use rand::prelude::*;
+use web_hex::Polyhedron;
+
+/// Radius drawn log-uniformly between a millimetre and twice the Earth's.
+fn random_radius(rng: &mut StdRng) -> f64 {
+    10f64.powf(rng.gen_range(-3.0..7.1))
+}
+
+/// Truncates `geodesic`, an icosahedral sphere of frequency `frequency`, and
+/// checks the invariants the result should have, `tolerance` being relative
+/// to `radius`.
+fn check_truncated<S: BaseFloat + std::fmt::Debug>(
+    geodesic: Polyhedron<S>,
+    radius: S,
+    frequency: usize,
+    tolerance: S,
+    label: &str,
+) {
+    for position in &geodesic.positions {
+        let distance = position.0.magnitude();
+        assert!(
+            ((distance - radius) / radius).abs() < tolerance,
+            "{label}: vertex at {distance:?}"
+        );
+    }
+    let directions: Vec<_> = geodesic.positions.iter().map(|p| p.0.normalize()).collect();
+    let mut polyhedron = geodesic.into_truncated().unwrap();
+    assert_eq!(
+        polyhedron.tile_count(),
+        10 * frequency * frequency + 2,
+        "{label}"
+    );
+    // Also checks that every triangle is wound outwards.
+    assert_eq!(polyhedron.validate(), Ok(()), "{label}");
+
+    let mut pentagons = 0;
+    for (tile_index, tile) in polyhedron.tiles().enumerate() {
+        let neighbours = tile.neighbours.len();
+        assert!(
+            neighbours == 5 || neighbours == 6,
+            "{label}: tile {tile_index}"
+        );
+        assert_eq!(
+            tile.boundary.len(),
+            neighbours,
+            "{label}: tile {tile_index}"
+        );
+        pentagons += (neighbours == 5) as usize;
+
+        // Tiles are flat, so their points sit just inside the sphere, with
+        // the centre nearer the geodesic vertex the tile replaces than any
+        // neighbouring one.
+        let center = polyhedron.tile_center(tile_index).normalize();
+        let own = center.dot(directions[tile_index]);
+        for neighbour in tile.neighbours {
+            assert!(
+                center.dot(directions[*neighbour as usize]) < own,
+                "{label}: tile {tile_index} is off centre"
+            );
+        }
+        for point in tile.boundary.iter().chain([&tile.center]) {
+            let distance = polyhedron.positions[*point as usize].0.magnitude();
+            assert!(
+                distance < radius * (S::one() + tolerance)
+                    && distance > radius * S::from(0.6).unwrap(),
+                "{label}: tile {tile_index} has a point at {distance:?}"
+            );
+        }
+    }
+    assert_eq!(pentagons, 12, "{label}");
+
+    polyhedron.compute_face_normals();
+    for normal in &polyhedron.normals {
+        assert!(
+            (normal.0.magnitude() - S::one()).abs() < tolerance,
+            "{label}"
+        );
+    }
+}
+
+#[test]
+fn geodesic_vertices_lie_on_the_sphere() {
+    let mut rng = StdRng::seed_from_u64(25);
+    for _ in 0..24 {
+        let radius = random_radius(&mut rng);
+        let (m, n) = (rng.gen_range(1..9), rng.gen_range(0..5));
+        let label = format!("GP({m}, {n}) at radius {radius}");
+        let polyhedron = Polyhedron::<f64>::new_goldberg_isocahedron(radius, m, n).unwrap();
+        let t = (m * m + m * n + n * n) as usize;
+        assert_eq!(polyhedron.positions.len(), 10 * t + 2, "{label}");
+        assert_eq!(polyhedron.validate(), Ok(()), "{label}");
+        for position in &polyhedron.positions {
+            assert!(
+                ((position.0.magnitude() - radius) / radius).abs() < 1e-12,
+                "{label}"
+            );
+        }
+    }
+}
+
+#[test]
+fn truncated_spheres_hold_their_invariants() {
+    let mut rng = StdRng::seed_from_u64(2024);
+    for detail in 0..5 {
+        for _ in 0..4 {
+            let radius = random_radius(&mut rng);
+            let geodesic = Polyhedron::<f64>::new_isocahedron(radius, detail).unwrap();
+            let label = format!("detail {detail} at radius {radius}");
+            check_truncated(geodesic, radius, 1 << detail, 1e-12, &label);
+        }
+    }
+    for _ in 0..12 {
+        let radius = random_radius(&mut rng);
+        let frequency = rng.gen_range(1..16);
+        let geodesic =
+            Polyhedron::<f64>::new_isocahedron_with_frequency(radius, frequency).unwrap();
+        let label = format!("frequency {frequency} at radius {radius}");
+        check_truncated(geodesic, radius, frequency as usize, 1e-12, &label);
+    }
+}
+
+#[test]
+fn single_precision_spheres_hold_their_invariants() {
+    let mut rng = StdRng::seed_from_u64(32);
+    for _ in 0..12 {
+        let radius = random_radius(&mut rng) as f32;
+        let frequency = rng.gen_range(1..12);
+        let geodesic =
+            Polyhedron::<f32>::new_isocahedron_with_frequency(radius, frequency).unwrap();
+        let label = format!("f32 frequency {frequency} at radius {radius}");
+        check_truncated(geodesic, radius, frequency as usize, 1e-5, &label);
+    }
+}
@@ -0,0 +1,54 @@
+use cgmath::InnerSpace;
+use web_hex::Polyhedron;
+
+/// Mean ratio of longest to shortest edge over all hexagons.
+fn hexagon_irregularity(polyhedron: &Polyhedron<f64>) -> f64 {
+    let hexagons: Vec<f64> = polyhedron
+        .tiles()
+        .filter(|tile| tile.is_hex())
+        .map(|tile| {
+            let edges: Vec<f64> = (0..tile.boundary.len())
+                .map(|k| {
+                    let [a, b] = [
+                        tile.boundary[k],
+                        tile.boundary[(k + 1) % tile.boundary.len()],
+                    ]
+                    .map(|corner| polyhedron.positions[corner as usize].0);
+                    (a - b).magnitude()
+                })
+                .collect();
+            edges.iter().cloned().fold(f64::MIN, f64::max)
+                / edges.iter().cloned().fold(f64::MAX, f64::min)
+        })
+        .collect();
+    hexagons.iter().sum::<f64>() / hexagons.len() as f64
+}
+
+#[test]
+fn relaxation_keeps_topology_and_regularises_hexagons() {
+    let geodesic = Polyhedron::<f64>::new_isocahedron_with_frequency(3.0, 8).unwrap();
+    let before = geodesic.clone().into_truncated().unwrap();
+
+    let mut relaxed = geodesic;
+    let passes = relaxed.relax(50, 1e-9).unwrap();
+    assert!(passes > 0 && passes <= 50);
+    for position in &relaxed.positions {
+        assert!((position.0.magnitude() - 3.0).abs() < 1e-9);
+    }
+    let after = relaxed.into_truncated().unwrap();
+
+    assert_eq!(after.tiles().len(), before.tiles().len());
+    assert_eq!(after.tiles().filter(|tile| !tile.is_hex()).count(), 12);
+    for (old, new) in before.tiles().zip(after.tiles()) {
+        assert_eq!(old.neighbours, new.neighbours);
+    }
+    assert!(hexagon_irregularity(&after) < hexagon_irregularity(&before));
+}
+
+#[test]
+fn relaxation_stops_at_tolerance() {
+    let mut geodesic = Polyhedron::<f64>::new_isocahedron_with_frequency(1.0, 4).unwrap();
+    let passes = geodesic.relax(10_000, 1e-6).unwrap();
+    assert!(passes < 10_000);
+    assert_eq!(geodesic.relax(10, 1e-6).unwrap(), 1);
+}
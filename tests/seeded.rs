@@ -0,0 +1,29 @@
+use web_hex::Polyhedron;
+
+fn coloured_sphere(seed: u64) -> String {
+    let mut polyhedron: Polyhedron = Polyhedron::new_truncated_isocahedron(10.0, 2).unwrap();
+    polyhedron.compute_face_normals();
+    polyhedron.assign_seeded_face_colors(seed);
+    serde_json::to_string(&polyhedron).unwrap()
+}
+
+#[test]
+fn same_seed_gives_identical_output() {
+    assert_eq!(coloured_sphere(7), coloured_sphere(7));
+}
+
+#[test]
+fn different_seeds_give_different_colours() {
+    assert_ne!(coloured_sphere(7), coloured_sphere(8));
+}
+
+#[test]
+fn seeded_colours_do_not_change_between_builds() {
+    let mut polyhedron: Polyhedron = Polyhedron::new_truncated_isocahedron(10.0, 2).unwrap();
+    polyhedron.assign_seeded_face_colors(7);
+    let first = polyhedron.colors[polyhedron.tile(0).center as usize].0;
+    assert_eq!(
+        [first.x, first.y, first.z],
+        [0.14000338, 0.15779608, 0.18205506]
+    );
+}
@@ -0,0 +1,100 @@
+use web_hex::{ArraySerializedVector, BaseSolid, Polyhedron, Projection};
+
+fn round_trip(polyhedron: &Polyhedron<f64>) -> Polyhedron<f64> {
+    serde_json::from_str(&serde_json::to_string(polyhedron).unwrap()).unwrap()
+}
+
+#[test]
+fn truncated_polyhedra_round_trip() {
+    let polyhedron = Polyhedron::<f64>::new_truncated_geodesic(
+        BaseSolid::Icosahedron,
+        2.0,
+        3,
+        1,
+        Projection::Slerp,
+    )
+    .unwrap();
+    let loaded = round_trip(&polyhedron);
+    assert_eq!(
+        serde_json::to_string(&loaded).unwrap(),
+        serde_json::to_string(&polyhedron).unwrap()
+    );
+    assert!(loaded.tiles().eq(polyhedron.tiles()));
+    for tile_index in 0..polyhedron.tile_count() {
+        let address = polyhedron.tile_address(tile_index).unwrap();
+        assert_eq!(loaded.tile_address(tile_index), Some(address));
+        assert_eq!(loaded.tile_at(address), Some(tile_index));
+    }
+
+    let stats = loaded.generation_stats();
+    assert_eq!((stats.hexagons, stats.pentagons), (10 * 13 - 10, 12));
+    assert_eq!(stats.vertices, polyhedron.positions.len());
+
+    // Everything built from the caches works on the loaded copy.
+    let locator = loaded.tile_locator();
+    for tile_index in 0..loaded.tile_count() {
+        assert_eq!(
+            locator.locate(loaded.tile_center(tile_index)),
+            Some(tile_index)
+        );
+    }
+    assert_eq!(loaded.hop_distance(0, 100), polyhedron.hop_distance(0, 100));
+}
+
+#[test]
+fn geodesic_polyhedra_truncate_the_same_after_loading() {
+    let polyhedron = Polyhedron::<f64>::new_octahedron_with_frequency(1.0, 4).unwrap();
+    let loaded = round_trip(&polyhedron);
+    assert_eq!(loaded.tile_address(7), polyhedron.tile_address(7));
+    assert_eq!(
+        serde_json::to_string(&loaded.into_truncated().unwrap()).unwrap(),
+        serde_json::to_string(&polyhedron.into_truncated().unwrap()).unwrap()
+    );
+}
+
+#[test]
+fn older_files_load_with_defaults() {
+    let d20: Polyhedron = serde_json::from_str(include_str!("../d20.json")).unwrap();
+    assert_eq!((d20.positions.len(), d20.cells.len()), (12, 20));
+    assert_eq!(d20.normals.len(), 12);
+    assert_eq!(d20.tile_address(0), None);
+    let truncated = d20.into_truncated().unwrap();
+    assert_eq!(truncated.generation_stats().pentagons, 12);
+
+    let flat: Polyhedron = serde_json::from_str(include_str!("../hexsphere_r10_d0.json")).unwrap();
+    assert_eq!(flat.cells.len(), 120);
+    assert_eq!(flat.tile_count(), 0);
+}
+
+#[test]
+fn addresses_are_worked_out_from_the_lattice() {
+    let polyhedron = Polyhedron::<f64>::new_truncated_goldberg_isocahedron(1.0, 2, 1).unwrap();
+    let json = serde_json::to_value(&polyhedron).unwrap();
+    assert!(json.get("addresses").is_none());
+
+    let loaded: Polyhedron<f64> = serde_json::from_value(json).unwrap();
+    for tile_index in 0..polyhedron.tile_count() {
+        let address = polyhedron.tile_address(tile_index).unwrap();
+        assert_eq!(loaded.tile_address(tile_index), Some(address));
+        assert_eq!(loaded.tile_at(address), Some(tile_index));
+    }
+}
+
+#[test]
+fn malformed_input_is_rejected() {
+    let broken = r#"{"positions": [[0, 0, 1], [0, 1, 0], [1, 0, 0]], "cells": [[0, 1, 3]]}"#;
+    let error = serde_json::from_str::<Polyhedron>(broken).unwrap_err();
+    assert!(error.to_string().contains("missing vertex 3"), "{error}");
+
+    let polyhedron = Polyhedron::<f64>::new_truncated_isocahedron(1.0, 1).unwrap();
+    let mut json = serde_json::to_value(&polyhedron).unwrap();
+    json["lattice"]["m"] = 3.into();
+    let error = serde_json::from_value::<Polyhedron<f64>>(json).unwrap_err();
+    assert!(error.to_string().contains("lattice"), "{error}");
+}
+
+#[test]
+fn vectors_read_back_their_array_form() {
+    let vector: ArraySerializedVector<f64> = serde_json::from_str("[1.5, -2.0, 3.25]").unwrap();
+    assert_eq!(serde_json::to_string(&vector).unwrap(), "[1.5,-2.0,3.25]");
+}
@@ -0,0 +1,43 @@
+use web_hex::{GenerationStats, Polyhedron};
+
+#[test]
+fn truncation_counts_tiles_and_geometry() {
+    let polyhedron = Polyhedron::<f64>::new_truncated_isocahedron_with_frequency(1.0, 3).unwrap();
+    let stats = polyhedron.generation_stats();
+    assert_eq!(
+        (stats.hexagons, stats.pentagons, stats.other_tiles),
+        (80, 12, 0)
+    );
+    assert_eq!(stats.vertices, polyhedron.positions.len());
+    assert_eq!(stats.triangles, polyhedron.cells.len());
+    assert!(stats.shared_corners > 0);
+
+    let octahedron = Polyhedron::<f64>::new_truncated_octahedron_with_frequency(1.0, 2).unwrap();
+    let stats = octahedron.generation_stats();
+    assert_eq!(
+        (stats.hexagons, stats.pentagons, stats.other_tiles),
+        (12, 0, 6)
+    );
+}
+
+#[test]
+fn subdivision_stats_accumulate_into_truncation() {
+    let geodesic = Polyhedron::<f64>::new_isocahedron_with_frequency(1.0, 4).unwrap();
+    let subdivided = *geodesic.generation_stats();
+    assert_eq!((subdivided.hexagons, subdivided.pentagons), (0, 0));
+    assert_eq!(subdivided.vertices, 10 * 4 * 4 + 2);
+    assert_eq!(subdivided.triangles, 20 * 4 * 4);
+
+    let truncated = geodesic.into_truncated().unwrap();
+    let stats = truncated.generation_stats();
+    assert!(stats.shared_corners > subdivided.shared_corners);
+    assert!(stats.duration >= subdivided.duration);
+}
+
+#[test]
+fn hand_built_polyhedra_have_empty_stats() {
+    assert_eq!(
+        *Polyhedron::<f64>::new().generation_stats(),
+        GenerationStats::default()
+    );
+}
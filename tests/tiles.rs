@@ -0,0 +1,70 @@
+use cgmath::{InnerSpace, Vector3};
+use web_hex::{PolyMesh, Polyhedron};
+
+#[test]
+fn tiles_follow_the_polyhedron() {
+    let polyhedron = Polyhedron::<f64>::new_truncated_goldberg_isocahedron(4.0, 2, 1).unwrap();
+    let tiles = polyhedron.to_tiles();
+    assert_eq!(tiles.radius, 4.0);
+    assert_eq!(tiles.tiles.len(), polyhedron.tiles().len());
+
+    let point = |vector: Vector3<f64>| vector.cast::<f32>().unwrap();
+    for (tile_index, (tile, topology)) in tiles.tiles.iter().zip(polyhedron.tiles()).enumerate() {
+        assert_eq!(tile.guid as usize, tile_index);
+        assert_eq!(tile.is_hex, topology.is_hex());
+        let center = point(polyhedron.tile_center(tile_index));
+        assert_eq!(
+            Vector3::new(
+                tile.center_point.x,
+                tile.center_point.y,
+                tile.center_point.z
+            ),
+            center
+        );
+        for (corner, position) in tile.boundary.iter().zip(topology.boundary) {
+            let position = point(polyhedron.positions[*position as usize].0);
+            assert_eq!(Vector3::new(corner.x, corner.y, corner.z), position);
+        }
+
+        // A fan over the boundary: corners - 2 triangles sharing corner 0.
+        let corners = tile.boundary.len() as u32;
+        assert_eq!(tile.indices.len() as u32, 3 * (corners - 2));
+        for (k, triangle) in tile.indices.chunks(3).enumerate() {
+            assert_eq!(triangle, [0, k as u32 + 1, k as u32 + 2]);
+        }
+
+        // Neighbours by guid, each across the matching boundary edge.
+        assert_eq!(tile.neighbours.len(), tile.boundary.len());
+        for (k, guid) in tile.neighbours.iter().enumerate() {
+            assert_eq!(*guid, topology.neighbours[k]);
+            let neighbour = &tiles.tiles[*guid as usize];
+            assert!(neighbour.neighbours.contains(&tile.guid));
+            let shared = |corner: &web_hex::Point| {
+                neighbour
+                    .boundary
+                    .iter()
+                    .any(|other| [other.x, other.y, other.z] == [corner.x, corner.y, corner.z])
+            };
+            assert!(shared(&tile.boundary[k]));
+            assert!(shared(&tile.boundary[(k + 1) % tile.boundary.len()]));
+        }
+    }
+}
+
+#[test]
+fn radius_is_measured_without_a_lattice() {
+    let polyhedron = Polyhedron::<f64>::new_truncated_isocahedron(2.5, 1).unwrap();
+    let chamfered = PolyMesh::from(&polyhedron)
+        .chamfer()
+        .unwrap()
+        .to_polyhedron()
+        .unwrap();
+    let farthest = chamfered
+        .positions
+        .iter()
+        .map(|position| position.0.magnitude())
+        .fold(0.0, f64::max);
+    assert_eq!(chamfered.radius(), farthest);
+    assert!(farthest < 2.5);
+    assert_eq!(chamfered.to_tiles().radius, farthest as f32);
+}
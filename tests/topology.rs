@@ -0,0 +1,57 @@
+use cgmath::InnerSpace;
+use web_hex::Polyhedron;
+
+#[test]
+fn each_boundary_edge_faces_its_neighbour() {
+    for detail in 0..3 {
+        let polyhedron = Polyhedron::<f64>::new_truncated_isocahedron(1.0, detail).unwrap();
+        for (tile_index, tile) in polyhedron.tiles().enumerate() {
+            assert_eq!(tile.boundary.len(), tile.neighbours.len());
+            for k in 0..tile.boundary.len() {
+                let (from, to) = (
+                    tile.boundary[k],
+                    tile.boundary[(k + 1) % tile.boundary.len()],
+                );
+                let neighbour = polyhedron.tile(tile.neighbours[k] as usize);
+                assert!(
+                    neighbour.boundary.contains(&from) && neighbour.boundary.contains(&to),
+                    "detail {detail}: tile {tile_index} edge {k}"
+                );
+                // The neighbour walks the same edge the other way round.
+                let back = neighbour
+                    .neighbours
+                    .iter()
+                    .position(|other| *other as usize == tile_index)
+                    .unwrap();
+                assert_eq!(neighbour.boundary[back], to);
+                assert_eq!(
+                    neighbour.boundary[(back + 1) % neighbour.boundary.len()],
+                    from
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn boundaries_wind_around_their_centers() {
+    let polyhedron = Polyhedron::<f64>::new_truncated_isocahedron(2.0, 2).unwrap();
+    let mut pentagons = 0;
+    for (tile_index, tile) in polyhedron.tiles().enumerate() {
+        let center = polyhedron.tile_center(tile_index);
+        assert_eq!(polyhedron.positions[tile.center as usize].0, center);
+        if !tile.is_hex() {
+            pentagons += 1;
+        }
+        // Anticlockwise seen from outside.
+        for k in 0..tile.boundary.len() {
+            let [a, b] = [
+                tile.boundary[k],
+                tile.boundary[(k + 1) % tile.boundary.len()],
+            ]
+            .map(|corner| polyhedron.positions[corner as usize].0);
+            assert!((a - center).cross(b - center).dot(center) > 0.0);
+        }
+    }
+    assert_eq!(pentagons, 12);
+}
@@ -0,0 +1,163 @@
+use web_hex::{BaseSolid, Polyhedron, Projection, ValidationIssue};
+
+#[test]
+fn generated_spheres_are_valid() {
+    for base in BaseSolid::ALL {
+        for (m, n) in [(1, 0), (2, 1), (4, 0)] {
+            let geodesic =
+                Polyhedron::<f64>::new_geodesic(base, 3.0, m, n, Projection::Slerp).unwrap();
+            assert_eq!(geodesic.validate(), Ok(()), "{base:?} GP({m}, {n})");
+            let truncated = geodesic.into_truncated().unwrap();
+            assert_eq!(truncated.validate(), Ok(()), "{base:?} GP({m}, {n})");
+            assert_eq!(truncated.to_tiles().validate(), Ok(()));
+        }
+    }
+}
+
+#[test]
+fn problems_are_reported_with_their_location() {
+    let mut polyhedron = Polyhedron::<f64>::new_isocahedron(1.0, 1).unwrap();
+    let cell = &mut polyhedron.cells[7];
+    std::mem::swap(&mut cell.b, &mut cell.c);
+    assert_eq!(
+        polyhedron.validate(),
+        Err(vec![ValidationIssue::InwardTriangle(7)])
+    );
+
+    let removed = polyhedron.cells.pop().unwrap();
+    let issues = polyhedron.validate().unwrap_err();
+    let mut corners = removed.corners();
+    corners.sort();
+    let [a, b, c] = corners;
+    assert_eq!(
+        issues,
+        vec![
+            ValidationIssue::OpenEdge { a, b, triangles: 1 },
+            ValidationIssue::OpenEdge {
+                a,
+                b: c,
+                triangles: 1
+            },
+            ValidationIssue::OpenEdge {
+                a: b,
+                b: c,
+                triangles: 1
+            },
+            ValidationIssue::InwardTriangle(7),
+            ValidationIssue::EulerCharacteristic(1),
+        ]
+    );
+    assert_eq!(
+        issues[0].to_string(),
+        format!("edge {a}-{b} is shared by 1 triangles")
+    );
+}
+
+#[test]
+fn tile_problems_are_reported() {
+    let polyhedron = Polyhedron::<f64>::new_truncated_isocahedron(1.0, 1).unwrap();
+    let mut json = serde_json::to_value(&polyhedron).unwrap();
+    json["tiles"][3]["neighbours"][0] = 41.into();
+    let tampered: Polyhedron<f64> = serde_json::from_value(json).unwrap();
+    let issues = tampered.validate().unwrap_err();
+    assert!(issues.contains(&ValidationIssue::AsymmetricNeighbours {
+        tile: 3,
+        neighbour: 41
+    }));
+    assert!(issues
+        .iter()
+        .all(|issue| matches!(issue, ValidationIssue::AsymmetricNeighbours { .. })));
+
+    let mut tiles = polyhedron.to_tiles();
+    let hexagon = tiles.tiles.iter().position(|tile| tile.is_hex).unwrap();
+    let pentagon = tiles.tiles.iter().position(|tile| !tile.is_hex).unwrap();
+    tiles.tiles[hexagon].indices.swap(1, 2);
+    let removed = tiles.tiles.remove(pentagon);
+    let pentagon_neighbours = removed.neighbours.len();
+    let issues = tiles.validate().unwrap_err();
+    let count = |check: fn(&ValidationIssue) -> bool| issues.iter().filter(|i| check(i)).count();
+    assert_eq!(
+        count(|i| matches!(i, ValidationIssue::OpenTileEdge { tiles: 1, .. })),
+        5
+    );
+    assert_eq!(
+        count(|i| matches!(i, ValidationIssue::InwardTile(_))),
+        1,
+        "{issues:?}"
+    );
+    assert_eq!(
+        count(|i| matches!(i, ValidationIssue::EulerCharacteristic(1))),
+        1
+    );
+    assert_eq!(
+        count(|i| matches!(i, ValidationIssue::NonHexagons(tiles) if tiles.len() == 11)),
+        1
+    );
+    let unknown: Vec<_> = issues
+        .iter()
+        .filter_map(|issue| match issue {
+            ValidationIssue::UnknownNeighbour { guid, .. } => Some(*guid),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(unknown, vec![removed.guid; pentagon_neighbours]);
+    assert_eq!(
+        count(|i| matches!(i, ValidationIssue::AsymmetricNeighbours { .. })),
+        0
+    );
+}
+
+#[test]
+fn flipped_edge_is_reported() {
+    // Flipping the edge between two hexagon vertices turns them into
+    // pentagons and the two vertices across it into heptagons. The sides
+    // still add up, but 14 pentagons and 2 heptagons are no icosahedron.
+    let mut geodesic = Polyhedron::<f64>::new_isocahedron(1.0, 2).unwrap();
+    let mut degrees = vec![0; geodesic.positions.len()];
+    for cell in &geodesic.cells {
+        for vertex in cell.corners() {
+            degrees[vertex] += 1;
+        }
+    }
+    let (first, second) = (0..geodesic.cells.len())
+        .flat_map(|first| (first + 1..geodesic.cells.len()).map(move |second| (first, second)))
+        .find(|&(first, second)| {
+            let (t, s) = (&geodesic.cells[first], &geodesic.cells[second]);
+            [t.a, t.b, t.c, s.a, s.b, s.c]
+                .iter()
+                .all(|vertex| degrees[*vertex as usize] == 6)
+                && [(t.a, t.b), (t.b, t.c), (t.c, t.a)]
+                    .iter()
+                    .filter(|(u, v)| [(s.a, s.b), (s.b, s.c), (s.c, s.a)].contains(&(*v, *u)))
+                    .count()
+                    == 1
+        })
+        .unwrap();
+    // Rotate the first triangle so that its edge u-v is the shared one.
+    let corners = |cell: &web_hex::Triangle| [cell.a, cell.b, cell.c];
+    let (t, s) = (
+        corners(&geodesic.cells[first]),
+        corners(&geodesic.cells[second]),
+    );
+    let k = (0..3).find(|k| !s.contains(&t[(k + 2) % 3])).unwrap();
+    let (u, v, w) = (t[k], t[(k + 1) % 3], t[(k + 2) % 3]);
+    let x = s
+        .into_iter()
+        .find(|vertex| *vertex != u && *vertex != v)
+        .unwrap();
+    for (index, [a, b, c]) in [(first, [u, x, w]), (second, [x, v, w])] {
+        let cell = &mut geodesic.cells[index];
+        (cell.a, cell.b, cell.c) = (a, b, c);
+    }
+    assert_eq!(geodesic.validate(), Ok(()));
+
+    let truncated = geodesic.into_truncated().unwrap();
+    let sides: Vec<usize> = truncated.tiles().map(|tile| tile.boundary.len()).collect();
+    assert_eq!(sides.iter().filter(|sides| **sides == 5).count(), 14);
+    assert_eq!(sides.iter().filter(|sides| **sides == 7).count(), 2);
+    let non_hexagons = (0..sides.len()).filter(|tile| sides[*tile] != 6).collect();
+    assert_eq!(
+        truncated.validate(),
+        Err(vec![ValidationIssue::NonHexagons(non_hexagons)])
+    );
+}
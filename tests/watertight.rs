@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use web_hex::Polyhedron;
+
+/// Counts how many triangles use each undirected edge.
+fn edge_counts(polyhedron: &Polyhedron) -> HashMap<(usize, usize), usize> {
+    let mut counts = HashMap::new();
+    for cell in &polyhedron.cells {
+        let [a, b, c] = cell.corners();
+        for (a, b) in [(a, b), (b, c), (c, a)] {
+            *counts.entry((a.min(b), a.max(b))).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+fn assert_watertight(polyhedron: &Polyhedron, label: &str) {
+    let counts = edge_counts(polyhedron);
+    let open_edges = counts.values().filter(|count| **count != 2).count();
+    assert_eq!(
+        open_edges, 0,
+        "{label}: edges not shared by exactly two triangles"
+    );
+
+    let euler =
+        polyhedron.positions.len() as i64 - counts.len() as i64 + polyhedron.cells.len() as i64;
+    assert_eq!(euler, 2, "{label}: V - E + F");
+}
+
+#[test]
+fn geodesic_spheres_are_watertight_at_any_radius() {
+    for radius in [0.0001, 1.0, 6_371_000.0] {
+        for (m, n) in [(1, 0), (5, 0), (16, 0), (1, 1), (3, 2)] {
+            let polyhedron = Polyhedron::new_goldberg_isocahedron(radius, m, n).unwrap();
+            let t = (m * m + m * n + n * n) as usize;
+            let label = format!("GP({m}, {n}) at radius {radius}");
+            assert_eq!(polyhedron.positions.len(), 10 * t + 2, "{label}");
+            assert_watertight(&polyhedron, &label);
+        }
+    }
+}
+
+#[test]
+fn truncated_spheres_are_watertight_at_any_radius() {
+    for radius in [0.0001, 1.0, 6_371_000.0] {
+        for detail in 0..5 {
+            let polyhedron = Polyhedron::new_truncated_isocahedron(radius, detail).unwrap();
+            assert_watertight(&polyhedron, &format!("detail {detail} at radius {radius}"));
+        }
+    }
+}